@@ -1,5 +1,5 @@
 use gnuplot::{AutoOption, AxesCommon, /* Caption, */ Color, Figure, PlotOption};
-use izhikevich_neurons::model::{NeuronConfig, StdpConfig};
+use izhikevich_neurons::model::{NeuronConfig, Receptor, StdpConfig};
 use izhikevich_neurons::network::{NetworkBuilder, SynapseDelay};
 use izhikevich_neurons::simulation::{Event, EventQueue, FireRecorder, Simulator};
 use izhikevich_neurons::Closed01;
@@ -20,17 +20,22 @@ fn main() {
         builder.create_n_neurons_with(1, &mut |_| NeuronConfig::regular_spiking());
 
     for _ in 1..10 {
-        builder.connect_all_with(&input_neurons, &hidden_neurons, &mut |_, _| {
-            Some((
-                SynapseDelay::new(rng.gen_range(2, 56)),
-                rng.gen_range(0.0, 5.0),
-            ))
-        });
+        builder.connect_all_with(
+            &input_neurons,
+            &hidden_neurons,
+            Receptor::Current,
+            &mut |_, _| {
+                Some((
+                    SynapseDelay::new(rng.gen_range(2, 56)),
+                    rng.gen_range(0.0, 5.0),
+                ))
+            },
+        );
     }
 
     let mut network = builder.into_network();
 
-    let mut sim = Simulator::new(network.max_synapse_delay(), StdpConfig::default());
+    let mut sim = Simulator::new(network.max_synapse_delay().unwrap(), StdpConfig::default());
 
     let mut external_inputs = EventQueue::new();
 
@@ -59,7 +64,7 @@ fn main() {
 
         if sim.current_time_step() % 500 == 0 {
             // Update synapse weights every 500 ms
-            network.update_synapse_weights(0.0, 10.0, 0.9);
+            sim.update_weights(&mut network);
         }
     }
 
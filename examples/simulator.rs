@@ -1,5 +1,5 @@
 use gnuplot::{AutoOption, AxesCommon, /*Caption,*/ Color, Figure, PlotOption};
-use izhikevich_neurons::model::{NeuronConfig, StdpConfig};
+use izhikevich_neurons::model::{NeuronConfig, Receptor, StdpConfig};
 use izhikevich_neurons::network::{NetworkBuilder, NeuronId, SynapseDelay};
 use izhikevich_neurons::simulation::{FireRecorder, Simulator, Timestep};
 use izhikevich_neurons::Num;
@@ -27,24 +27,28 @@ fn main() {
         &middle_neurons[..],
         SynapseDelay::new(2),
         2.0,
+        Receptor::Current,
     );
     builder.connect_all(
         &input_neurons[..],
         &middle_neurons[..],
         SynapseDelay::new(3),
         2.0,
+        Receptor::Current,
     );
     builder.connect_all(
         &input_neurons[..],
         &middle_neurons[..],
         SynapseDelay::new(5),
         2.0,
+        Receptor::Current,
     );
     builder.connect_all(
         &middle_neurons[..],
         &output_neurons[..],
         SynapseDelay::new(1),
         1.0,
+        Receptor::Current,
     );
 
     let pattern1: [u8; 9] = [0, 0, 0, 1, 1, 1, 0, 0, 0];
@@ -70,7 +74,7 @@ fn main() {
     }
 
     let mut network = builder.into_network();
-    let mut sim = Simulator::new(network.max_synapse_delay(), StdpConfig::default());
+    let mut sim = Simulator::new(network.max_synapse_delay().unwrap(), StdpConfig::default());
 
     while sim.current_time_step() <= 10_000 {
         // set external inputs
@@ -86,7 +90,7 @@ fn main() {
         if sim.current_time_step() % 500 == 0 {
             // Update synapse weights every 10 ms
             if sim.current_time_step() < 6001 {
-                network.update_synapse_weights(0.0, 10.0, 0.9);
+                sim.update_weights(&mut network);
             }
         }
     }
@@ -0,0 +1,113 @@
+// Izhikevich's spnet, as given in the article
+//
+// Polychronization: Computing with Spikes (2006).
+//
+// 800 excitatory and 200 inhibitory neurons with 100 synapses each. The
+// excitatory synapses have delays of 1 to 20 ms and are subject to STDP,
+// the inhibitory ones have a delay of 1 ms and fixed weights.
+
+use izhikevich_neurons::model::{NeuronType, StdpConfig};
+use izhikevich_neurons::network::{
+    Connector, DelayDistribution, NetworkBuilder, SynapseConfig, SynapseDelay, Topology,
+};
+use izhikevich_neurons::plasticity::PairStdp;
+use izhikevich_neurons::simulation::{FireRecorder, Noise, Simulator};
+use izhikevich_neurons::Num;
+
+const M: usize = 100; // number of synapses per neuron
+const D: u8 = 20; // maximal conduction delay
+const NE: usize = 800; // total number of excitatory neurons
+const NI: usize = 200; // total number of inhibitory neurons
+const SECONDS: usize = 10;
+
+fn main() {
+    let mut builder = NetworkBuilder::new();
+
+    let exc = builder.create_population("excitatory", NE, NeuronType::RegularSpiking, 0);
+    let inh = builder.create_population("inhibitory", NI, NeuronType::FastSpiking, 1);
+
+    // excitatory neurons connect to both kinds, in proportion to their size
+    let delays = DelayDistribution::Uniform {
+        min: SynapseDelay::new(1),
+        max: SynapseDelay::new(D),
+    };
+    builder.project(
+        "exc->exc",
+        exc,
+        exc,
+        &Connector::new(Topology::FixedOutDegree(M * NE / (NE + NI)), 6.0)
+            .with_delays(delays)
+            .with_seed(2),
+    );
+    builder.project(
+        "exc->inh",
+        exc,
+        inh,
+        &Connector::new(Topology::FixedOutDegree(M * NI / (NE + NI)), 6.0)
+            .with_delays(delays)
+            .with_seed(3),
+    );
+
+    // inhibitory neurons only connect to excitatory neurons
+    builder.project(
+        "inh->exc",
+        inh,
+        exc,
+        &Connector::new(Topology::FixedOutDegree(M), -5.0)
+            .with_synapse(SynapseConfig::default().fixed())
+            .with_seed(4),
+    );
+
+    let mut network = builder.into_network();
+    let mut sim = Simulator::new(SynapseDelay::new(D + 1), StdpConfig::default());
+
+    // depression is 20% stronger than potentiation, as in spnet
+    sim.set_plasticity_rule(PairStdp::additive(0.1, 0.12, 20.0, 20.0));
+
+    // random thalamic input: on average one neuron per ms receives 20 mV
+    let thalamic_input = Noise::Poisson {
+        rate: 1000.0 / (NE + NI) as Num,
+        weight: 20.0,
+    };
+    for (seed, population) in network.populations().iter().enumerate() {
+        sim.add_noise(thalamic_input, population.neurons(), seed as u64);
+    }
+
+    for second in 0..SECONDS {
+        let mut recorder = FireRecorder::new();
+        for _ in 0..1000 {
+            sim.step(&mut network, &mut recorder);
+            if sim.current_time_step() % 1000 == 0 {
+                sim.update_weights(&mut network);
+            }
+        }
+
+        let rates: Vec<String> = network
+            .populations()
+            .iter()
+            .map(|population| {
+                let spikes = recorder.population_events(population).len();
+                format!(
+                    "{}: {:.1} Hz",
+                    population.name(),
+                    spikes as Num / population.size() as Num
+                )
+            })
+            .collect();
+
+        let projection = network.projection(network.find_projection("exc->exc").unwrap());
+        let mean_weight = projection
+            .synapses()
+            .iter()
+            .map(|&synapse_id| network.synapse_ref(synapse_id).weight())
+            .sum::<Num>()
+            / projection.synapses().len() as Num;
+
+        println!(
+            "second {}: {}, mean exc->exc weight: {:.2}",
+            second + 1,
+            rates.join(", "),
+            mean_weight
+        );
+    }
+}
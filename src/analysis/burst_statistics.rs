@@ -0,0 +1,125 @@
+use crate::Num;
+
+/// Minimum ratio between the shortest inter-burst and the longest
+/// intra-burst interval.
+const BURST_SEPARATION: Num = 2.5;
+
+/// Intra-burst intervals must be shorter than this (in ms).
+pub(crate) const MAX_INTRA_BURST_INTERVAL: Num = 15.0;
+
+/// The bursts of a spike train and their statistics.
+///
+/// Bursts are detected from the distribution of inter-spike intervals: if
+/// there is a gap between short and long intervals of at least a factor of
+/// 2.5, spikes separated by short intervals (below 15 ms) form a burst. A
+/// burst has at least two spikes.
+#[derive(Debug, Clone, Default)]
+pub struct BurstStatistics {
+    /// The spike times of each burst (in ms).
+    pub bursts: Vec<Vec<Num>>,
+
+    /// Spikes outside of bursts.
+    pub single_spikes: usize,
+
+    /// Mean number of spikes per burst.
+    pub spikes_per_burst: Num,
+
+    /// Mean interval between spikes within a burst (in ms).
+    pub intra_burst_interval: Num,
+
+    /// Mean interval between the last spike of a burst and the first spike
+    /// of the next burst (in ms).
+    pub inter_burst_interval: Num,
+}
+
+/// The largest inter-spike interval that still belongs to a burst, if the
+/// intervals separate into short and long ones.
+fn burst_threshold(spike_times: &[Num]) -> Option<Num> {
+    let mut intervals: Vec<Num> = spike_times.windows(2).map(|w| w[1] - w[0]).collect();
+    intervals.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    if intervals
+        .first()
+        .is_none_or(|&shortest| shortest >= MAX_INTRA_BURST_INTERVAL)
+    {
+        return None;
+    }
+    // the largest ratio of consecutive intervals among the short ones
+    let (index, ratio) = intervals
+        .windows(2)
+        .enumerate()
+        .filter(|(_, w)| w[0] < MAX_INTRA_BURST_INTERVAL)
+        .map(|(i, w)| (i, w[1] / w[0]))
+        .fold((0, 0.0), |best, candidate| {
+            if candidate.1 > best.1 {
+                candidate
+            } else {
+                best
+            }
+        });
+    if ratio >= BURST_SEPARATION {
+        Some(intervals[index])
+    } else if intervals.len() == 1 {
+        // a single pair of spikes
+        Some(intervals[0])
+    } else {
+        None
+    }
+}
+
+impl BurstStatistics {
+    /// The bursts of the sorted `spike_times` (in ms).
+    pub fn of(spike_times: &[Num]) -> BurstStatistics {
+        let threshold = match burst_threshold(spike_times) {
+            Some(threshold) => threshold,
+            None => {
+                return BurstStatistics {
+                    single_spikes: spike_times.len(),
+                    ..Default::default()
+                }
+            }
+        };
+
+        let mut groups: Vec<Vec<Num>> = Vec::new();
+        for &t in spike_times {
+            match groups.last_mut() {
+                Some(group) if t - group[group.len() - 1] <= threshold => group.push(t),
+                _ => groups.push(vec![t]),
+            }
+        }
+        let (bursts, singles): (Vec<Vec<Num>>, Vec<Vec<Num>>) =
+            groups.into_iter().partition(|group| group.len() >= 2);
+
+        let mean = |values: Vec<Num>| {
+            if values.is_empty() {
+                0.0
+            } else {
+                values.iter().sum::<Num>() / values.len() as Num
+            }
+        };
+        let spikes_per_burst = mean(bursts.iter().map(|b| b.len() as Num).collect());
+        let intra_burst_interval = mean(
+            bursts
+                .iter()
+                .flat_map(|b| b.windows(2).map(|w| w[1] - w[0]))
+                .collect(),
+        );
+        let inter_burst_interval = mean(
+            bursts
+                .windows(2)
+                .map(|w| w[1][0] - w[0][w[0].len() - 1])
+                .collect(),
+        );
+
+        BurstStatistics {
+            bursts,
+            single_spikes: singles.len(),
+            spikes_per_burst,
+            intra_burst_interval,
+            inter_burst_interval,
+        }
+    }
+
+    pub fn is_bursting(&self) -> bool {
+        !self.bursts.is_empty()
+    }
+}
@@ -0,0 +1,46 @@
+use crate::Num;
+
+/// A (possibly complex) eigenvalue `re + i * im`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Eigenvalue {
+    pub re: Num,
+    pub im: Num,
+}
+
+impl Eigenvalue {
+    /// The eigenvalues of the 2x2 matrix `m`.
+    pub(crate) fn of(m: [[Num; 2]; 2]) -> [Eigenvalue; 2] {
+        let trace = m[0][0] + m[1][1];
+        let det = m[0][0] * m[1][1] - m[0][1] * m[1][0];
+        let discriminant = trace * trace / 4.0 - det;
+        if discriminant >= 0.0 {
+            let root = discriminant.sqrt();
+            [
+                Eigenvalue {
+                    re: trace / 2.0 - root,
+                    im: 0.0,
+                },
+                Eigenvalue {
+                    re: trace / 2.0 + root,
+                    im: 0.0,
+                },
+            ]
+        } else {
+            let root = (-discriminant).sqrt();
+            [
+                Eigenvalue {
+                    re: trace / 2.0,
+                    im: -root,
+                },
+                Eigenvalue {
+                    re: trace / 2.0,
+                    im: root,
+                },
+            ]
+        }
+    }
+
+    pub fn is_real(&self) -> bool {
+        self.im == 0.0
+    }
+}
@@ -0,0 +1,127 @@
+use crate::analysis::burst_statistics::MAX_INTRA_BURST_INTERVAL;
+use crate::analysis::BurstStatistics;
+use crate::model::NeuronState;
+use crate::Num;
+
+/// The behaviour of a neuron under a step current, after Izhikevich (2004).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FiringPattern {
+    /// No spikes.
+    Silent,
+
+    /// A single spike, or a few spikes at the onset of the step.
+    PhasicSpiking,
+
+    /// Regular spikes throughout the step.
+    TonicSpiking,
+
+    /// Tonic spiking with increasing inter-spike intervals.
+    Adapting,
+
+    /// Tonic spiking at high frequency (above 100 Hz) without adaptation.
+    FastSpiking,
+
+    /// A single burst at the onset of the step.
+    PhasicBursting,
+
+    /// A burst of at least three spikes at the onset followed by single
+    /// spikes (intrinsically bursting cells).
+    MixedMode,
+
+    /// Repetitive bursts.
+    TonicBursting,
+
+    /// Repetitive bursts at high frequency (period of at most 50 ms).
+    Chattering,
+}
+
+/// Minimum ratio of the last to the first inter-spike interval of an
+/// adapting neuron.
+const ADAPTATION_RATIO: Num = 2.0;
+
+/// Minimum firing rate of fast spiking neurons (in Hz).
+const FAST_SPIKING_RATE: Num = 100.0;
+
+/// Maximum burst period of chattering neurons (in ms).
+const CHATTERING_PERIOD: Num = 50.0;
+
+/// The spike times (in ms) of a voltage trace, sampled every `dt` ms.
+/// A spike is an upward crossing of the threshold at which the neuron fires.
+pub fn spike_times_of_trace(trace: &[NeuronState], dt: Num) -> Vec<Num> {
+    let mut above = false;
+    let mut spike_times = Vec::new();
+    for (i, state) in trace.iter().enumerate() {
+        if state.above_threshold() && !above {
+            spike_times.push(i as Num * dt);
+        }
+        above = state.above_threshold();
+    }
+    spike_times
+}
+
+impl FiringPattern {
+    /// Classifies the sorted `spike_times` (in ms) of a neuron receiving a
+    /// step current from `onset` to `end` (in ms).
+    pub fn classify(spike_times: &[Num], onset: Num, end: Num) -> FiringPattern {
+        let spike_times: Vec<Num> = spike_times
+            .iter()
+            .copied()
+            .filter(|&t| t >= onset && t <= end)
+            .collect();
+        let bursts = BurstStatistics::of(&spike_times);
+        let last_spike = match spike_times.last() {
+            Some(&t) => t,
+            None => return FiringPattern::Silent,
+        };
+        let intervals: Vec<Num> = spike_times.windows(2).map(|w| w[1] - w[0]).collect();
+
+        // Firing has ceased long before the end of the step.
+        let longest_interval = intervals.iter().copied().fold(0.0, Num::max);
+        let phasic =
+            end - last_spike > (end - onset) / 2.0 && end - last_spike > 2.0 * longest_interval;
+
+        if phasic && bursts.bursts.len() <= 1 {
+            // A high-frequency train without a gap has no bursts, but is a
+            // burst if it stops early.
+            let burst = match bursts.bursts.first() {
+                Some(burst) => burst.len() == spike_times.len(),
+                None => spike_times.len() >= 3 && longest_interval < MAX_INTRA_BURST_INTERVAL,
+            };
+            return if burst {
+                FiringPattern::PhasicBursting
+            } else {
+                FiringPattern::PhasicSpiking
+            };
+        }
+
+        match bursts.bursts.len() {
+            0 => {}
+            1 => {
+                if bursts.bursts[0].len() >= 3 && bursts.single_spikes > 0 {
+                    return FiringPattern::MixedMode;
+                }
+            }
+            n => {
+                let period = (bursts.bursts[n - 1][0] - bursts.bursts[0][0]) / (n - 1) as Num;
+                return if period <= CHATTERING_PERIOD {
+                    FiringPattern::Chattering
+                } else {
+                    FiringPattern::TonicBursting
+                };
+            }
+        }
+
+        if spike_times.len() < 3 {
+            return FiringPattern::PhasicSpiking;
+        }
+        if intervals[intervals.len() - 1] >= ADAPTATION_RATIO * intervals[0] {
+            return FiringPattern::Adapting;
+        }
+        let mean_interval = intervals.iter().sum::<Num>() / intervals.len() as Num;
+        if 1000.0 / mean_interval > FAST_SPIKING_RATE {
+            FiringPattern::FastSpiking
+        } else {
+            FiringPattern::TonicSpiking
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use crate::analysis::{Eigenvalue, Stability};
+use crate::model::NeuronState;
+use crate::Num;
+
+/// An equilibrium of a neuron receiving a constant input current.
+#[derive(Debug, Copy, Clone)]
+pub struct FixedPoint {
+    /// membrane potential (in mV)
+    pub v: Num,
+
+    /// recovery variable
+    pub u: Num,
+
+    /// The eigenvalues of the Jacobian at the fixed point, in ascending
+    /// order of their real (or imaginary) part.
+    pub eigenvalues: [Eigenvalue; 2],
+
+    pub stability: Stability,
+}
+
+impl FixedPoint {
+    pub fn state(&self) -> NeuronState {
+        NeuronState::with(self.v, self.u)
+    }
+}
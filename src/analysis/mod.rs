@@ -0,0 +1,26 @@
+pub mod burst_statistics;
+pub mod eigenvalue;
+pub mod firing_pattern;
+pub mod fixed_point;
+pub mod phase_plane;
+pub mod phase_response_curve;
+pub mod rheobase;
+pub mod stability;
+pub mod step_response;
+pub mod vector_field_sample;
+
+pub use burst_statistics::BurstStatistics;
+pub use eigenvalue::Eigenvalue;
+pub use firing_pattern::{spike_times_of_trace, FiringPattern};
+pub use fixed_point::FixedPoint;
+pub use phase_plane::PhasePlane;
+pub use phase_response_curve::phase_response_curve;
+pub use rheobase::{rheobase, saddle_node_current};
+pub use stability::Stability;
+pub use step_response::{fi_curve, StepResponse};
+pub use vector_field_sample::VectorFieldSample;
+
+use crate::Num;
+
+/// Time step of the simulations used by the analyses (in ms).
+pub(crate) const DT: Num = 0.1;
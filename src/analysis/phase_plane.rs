@@ -0,0 +1,166 @@
+use crate::analysis::{Eigenvalue, FixedPoint, Stability, VectorFieldSample};
+use crate::model::neuron_state::{du, dv};
+use crate::model::{Integrator, NeuronConfig, NeuronState};
+use crate::Num;
+
+/// The phase plane `(v, u)` of a neuron configured with `config`, which
+/// receives the constant input `current`.
+#[derive(Debug, Clone)]
+pub struct PhasePlane<'a> {
+    config: &'a NeuronConfig,
+    current: Num,
+}
+
+/// `n` values evenly spaced from `from` to `to`.
+fn linspace(from: Num, to: Num, n: usize) -> impl Iterator<Item = Num> {
+    let step = if n > 1 {
+        (to - from) / (n - 1) as Num
+    } else {
+        0.0
+    };
+    (0..n).map(move |i| from + i as Num * step)
+}
+
+impl<'a> PhasePlane<'a> {
+    pub fn new(config: &'a NeuronConfig, current: Num) -> Self {
+        PhasePlane { config, current }
+    }
+
+    pub fn config(&self) -> &NeuronConfig {
+        self.config
+    }
+
+    pub fn current(&self) -> Num {
+        self.current
+    }
+
+    /// The value of `u` for which `dv/dt = 0` at potential `v`.
+    pub fn v_nullcline(&self, v: Num) -> Num {
+        let [k2, k1, k0] = self.config.membrane;
+        (k2 * v + k1) * v + k0 + self.current
+    }
+
+    /// The value of `u` for which `du/dt = 0` at potential `v`. For the
+    /// accommodating cell, `du/dt` vanishes only on the vertical line
+    /// `v = -65`, which is reported as `None`.
+    pub fn u_nullcline(&self, v: Num) -> Option<Num> {
+        if self.config.accommodation {
+            None
+        } else {
+            Some(self.config.b * v)
+        }
+    }
+
+    /// Samples the v-nullcline at `n` potentials from `v_min` to `v_max`,
+    /// as points `(v, u)`.
+    pub fn sample_v_nullcline(&self, v_min: Num, v_max: Num, n: usize) -> Vec<(Num, Num)> {
+        linspace(v_min, v_max, n)
+            .map(|v| (v, self.v_nullcline(v)))
+            .collect()
+    }
+
+    /// Samples the u-nullcline at `n` potentials from `v_min` to `v_max`,
+    /// as points `(v, u)`. Empty for the accommodating cell.
+    pub fn sample_u_nullcline(&self, v_min: Num, v_max: Num, n: usize) -> Vec<(Num, Num)> {
+        linspace(v_min, v_max, n)
+            .filter_map(|v| self.u_nullcline(v).map(|u| (v, u)))
+            .collect()
+    }
+
+    /// The Jacobian `[[dv'/dv, dv'/du], [du'/dv, du'/du]]` at potential `v`.
+    pub fn jacobian(&self, v: Num) -> [[Num; 2]; 2] {
+        let c = self.config;
+        let [k2, k1, _] = c.membrane;
+        let du_du = if c.accommodation { 0.0 } else { -c.a };
+        [[2.0 * k2 * v + k1, -1.0], [c.a * c.b, du_du]]
+    }
+
+    /// The fixed points, i.e. the intersections of the nullclines, in
+    /// ascending order of `v`.
+    pub fn fixed_points(&self) -> Vec<FixedPoint> {
+        let c = self.config;
+        let [k2, k1, k0] = c.membrane;
+
+        let potentials = if c.accommodation {
+            vec![-65.0]
+        } else {
+            // k2 * v^2 + (k1 - b) * v + k0 + I = 0
+            let p = (k1 - c.b) / k2;
+            let q = (k0 + self.current) / k2;
+            let discriminant = p * p / 4.0 - q;
+            if discriminant < 0.0 {
+                vec![]
+            } else if discriminant == 0.0 {
+                vec![-p / 2.0]
+            } else {
+                let root = discriminant.sqrt();
+                vec![-p / 2.0 - root, -p / 2.0 + root]
+            }
+        };
+
+        potentials
+            .into_iter()
+            .map(|v| {
+                let eigenvalues = Eigenvalue::of(self.jacobian(v));
+                FixedPoint {
+                    v,
+                    u: self.v_nullcline(v),
+                    eigenvalues,
+                    stability: Stability::of(eigenvalues),
+                }
+            })
+            .collect()
+    }
+
+    /// The stable fixed point with the lowest potential, if any.
+    pub fn resting_state(&self) -> Option<FixedPoint> {
+        self.fixed_points()
+            .into_iter()
+            .find(|fixed_point| fixed_point.stability.is_stable())
+    }
+
+    /// The direction of motion at `(v, u)`.
+    pub fn vector_at(&self, v: Num, u: Num) -> VectorFieldSample {
+        VectorFieldSample {
+            v,
+            u,
+            dv: dv(u, v, self.current, self.config),
+            du: du(u, v, self.config),
+        }
+    }
+
+    /// Samples the vector field on a grid of `n_v` x `n_u` points spanning
+    /// `v_min..=v_max` and `u_min..=u_max`.
+    pub fn vector_field(
+        &self,
+        (v_min, v_max): (Num, Num),
+        (u_min, u_max): (Num, Num),
+        (n_v, n_u): (usize, usize),
+    ) -> Vec<VectorFieldSample> {
+        linspace(u_min, u_max, n_u)
+            .flat_map(|u| linspace(v_min, v_max, n_v).map(move |v| (v, u)))
+            .map(|(v, u)| self.vector_at(v, u))
+            .collect()
+    }
+
+    /// The trajectory starting in `initial_state`, integrated with
+    /// `integrator` in steps of `dt` ms for `duration` ms. Returns the
+    /// states together with their time (in ms), starting at `t = 0`.
+    pub fn trajectory(
+        &self,
+        initial_state: NeuronState,
+        integrator: Integrator,
+        dt: Num,
+        duration: Num,
+    ) -> Vec<(Num, NeuronState)> {
+        let steps = (duration / dt).round() as usize;
+        let mut state = initial_state;
+        let mut trajectory = Vec::with_capacity(steps + 1);
+        trajectory.push((0.0, state));
+        for i in 1..=steps {
+            state = state.integrate(integrator, dt, self.current, self.config).0;
+            trajectory.push((i as Num * dt, state));
+        }
+        trajectory
+    }
+}
@@ -0,0 +1,74 @@
+use crate::analysis::step_response::resting_state;
+use crate::analysis::DT;
+use crate::model::{Integrator, NeuronConfig, NeuronState};
+use crate::Num;
+
+/// Spikes to skip until the firing is considered periodic.
+const TRANSIENT_SPIKES: usize = 20;
+
+/// The phase response curve of a neuron which fires tonically under the
+/// constant input `current`.
+///
+/// At each of `n` phases evenly spaced in `[0, 1)`, the neuron receives an
+/// additional current pulse of `amplitude` for `pulse_duration` ms. Returns
+/// the pairs `(phase, phase shift)`, where the shift is the advance of the
+/// next spike relative to the unperturbed period (positive when the spike
+/// comes earlier). The shift is `None` if the pulse suppresses the next
+/// spike for more than two periods. Returns `None` if the neuron does not
+/// fire periodically.
+pub fn phase_response_curve(
+    config: &NeuronConfig,
+    current: Num,
+    amplitude: Num,
+    pulse_duration: Num,
+    n: usize,
+) -> Option<Vec<(Num, Option<Num>)>> {
+    let integrator = Integrator::default();
+    let step = |state: NeuronState, input: Num| state.integrate(integrator, DT, input, config);
+
+    // Run into the limit cycle and measure its period in steps.
+    let mut state = resting_state(config);
+    let mut spikes = 0;
+    let mut since_spike = 0;
+    let mut period = 0;
+    let max_steps = (10_000.0 / DT) as usize;
+    for _ in 0..max_steps {
+        let (new_state, activity) = step(state, current);
+        state = new_state;
+        since_spike += 1;
+        if activity.fires() {
+            spikes += 1;
+            period = since_spike;
+            since_spike = 0;
+            if spikes > TRANSIENT_SPIKES {
+                break;
+            }
+        }
+    }
+    if spikes <= TRANSIENT_SPIKES {
+        return None;
+    }
+    let spike_state = state;
+    let pulse_steps = ((pulse_duration / DT).round() as usize).max(1);
+
+    let curve = (0..n)
+        .map(|i| {
+            let phase = i as Num / n as Num;
+            let onset = (phase * period as Num).round() as usize;
+            let mut state = spike_state;
+            let next_spike = (1..=2 * period + pulse_steps).find(|&steps| {
+                let pulse = if steps > onset && steps <= onset + pulse_steps {
+                    amplitude
+                } else {
+                    0.0
+                };
+                let (new_state, activity) = step(state, current + pulse);
+                state = new_state;
+                activity.fires()
+            });
+            let shift = next_spike.map(|steps| (period as Num - steps as Num) / period as Num);
+            (phase, shift)
+        })
+        .collect();
+    Some(curve)
+}
@@ -0,0 +1,54 @@
+use crate::analysis::step_response::resting_state;
+use crate::analysis::DT;
+use crate::model::{Integrator, NeuronConfig};
+use crate::Num;
+
+/// Duration of the current step used to test for a spike (in ms).
+const TEST_DURATION: Num = 1000.0;
+
+/// The input current at which the resting state disappears in a
+/// saddle-node bifurcation, if there is one. Neurons with an Andronov-Hopf
+/// bifurcation (e.g. resonators) may fire at lower currents.
+pub fn saddle_node_current(config: &NeuronConfig) -> Option<Num> {
+    if config.accommodation {
+        return None;
+    }
+    let [k2, k1, k0] = config.membrane;
+    Some((k1 - config.b) * (k1 - config.b) / (4.0 * k2) - k0)
+}
+
+/// Whether a neuron at rest fires within `TEST_DURATION` ms after the onset
+/// of a current step to `current`.
+fn fires(config: &NeuronConfig, current: Num) -> bool {
+    let mut state = resting_state(config);
+    let steps = (TEST_DURATION / DT) as usize;
+    let integrator = Integrator::default();
+    (0..steps).any(|_| {
+        let (new_state, activity) = state.integrate(integrator, DT, current, config);
+        state = new_state;
+        activity.fires()
+    })
+}
+
+/// The rheobase, i.e. the smallest step current that makes a neuron at rest
+/// fire within one second, determined by bisection to within `tolerance`.
+/// Returns `None` if it does not fire for currents up to `max_current`.
+pub fn rheobase(config: &NeuronConfig, max_current: Num, tolerance: Num) -> Option<Num> {
+    assert!(tolerance > 0.0);
+    if !fires(config, max_current) {
+        return None;
+    }
+    let (mut low, mut high) = (0.0, max_current);
+    if fires(config, low) {
+        return Some(low);
+    }
+    while high - low > tolerance {
+        let mid = (low + high) / 2.0;
+        if fires(config, mid) {
+            high = mid;
+        } else {
+            low = mid;
+        }
+    }
+    Some(high)
+}
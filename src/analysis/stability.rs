@@ -0,0 +1,49 @@
+use crate::analysis::Eigenvalue;
+
+/// The type of a fixed point of a two-dimensional system.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Stability {
+    /// Both eigenvalues are real and negative.
+    StableNode,
+
+    /// Complex eigenvalues with negative real part. Trajectories spiral
+    /// into the fixed point, i.e. the neuron shows damped oscillations.
+    StableFocus,
+
+    /// Both eigenvalues are real and positive.
+    UnstableNode,
+
+    /// Complex eigenvalues with positive real part.
+    UnstableFocus,
+
+    /// Real eigenvalues of opposite sign.
+    Saddle,
+
+    /// An eigenvalue has zero real part, e.g. at a bifurcation.
+    NonHyperbolic,
+}
+
+impl Stability {
+    pub(crate) fn of(eigenvalues: [Eigenvalue; 2]) -> Stability {
+        let [e1, e2] = eigenvalues;
+        if e1.re == 0.0 || e2.re == 0.0 {
+            Stability::NonHyperbolic
+        } else if !e1.is_real() {
+            if e1.re < 0.0 {
+                Stability::StableFocus
+            } else {
+                Stability::UnstableFocus
+            }
+        } else if e1.re < 0.0 && e2.re < 0.0 {
+            Stability::StableNode
+        } else if e1.re > 0.0 && e2.re > 0.0 {
+            Stability::UnstableNode
+        } else {
+            Stability::Saddle
+        }
+    }
+
+    pub fn is_stable(&self) -> bool {
+        matches!(self, Stability::StableNode | Stability::StableFocus)
+    }
+}
@@ -0,0 +1,125 @@
+use crate::analysis::{BurstStatistics, FiringPattern, PhasePlane, DT};
+use crate::model::{Integrator, NeuronConfig, NeuronState};
+use crate::Num;
+
+/// The spiking of a neuron at rest in response to a step of constant
+/// current, which starts at `t = 0`.
+#[derive(Debug, Clone)]
+pub struct StepResponse {
+    /// The amplitude of the current step.
+    pub current: Num,
+
+    /// Duration of the step (in ms).
+    pub duration: Num,
+
+    /// The spike times (in ms).
+    pub spike_times: Vec<Num>,
+}
+
+/// The resting state of a neuron without input, or the default state if
+/// it has no stable fixed point.
+pub(crate) fn resting_state(config: &NeuronConfig) -> NeuronState {
+    PhasePlane::new(config, 0.0)
+        .resting_state()
+        .map_or(NeuronState::new(), |rest| rest.state())
+}
+
+impl StepResponse {
+    /// Simulates a neuron configured with `config` receiving `current` for
+    /// `duration` ms.
+    pub fn measure(config: &NeuronConfig, current: Num, duration: Num) -> StepResponse {
+        let mut state = resting_state(config);
+        let integrator = Integrator::default();
+        let steps = (duration / DT).round() as usize;
+        let mut spike_times = Vec::new();
+        for i in 1..=steps {
+            let (new_state, activity) = state.integrate(integrator, DT, current, config);
+            state = new_state;
+            if activity.fires() {
+                spike_times.push(i as Num * DT);
+            }
+        }
+        StepResponse {
+            current,
+            duration,
+            spike_times,
+        }
+    }
+
+    /// The inter-spike intervals (in ms).
+    pub fn intervals(&self) -> Vec<Num> {
+        self.spike_times.windows(2).map(|w| w[1] - w[0]).collect()
+    }
+
+    /// The time from the onset of the step to the first spike (in ms).
+    pub fn first_spike_latency(&self) -> Option<Num> {
+        self.spike_times.first().copied()
+    }
+
+    /// The steady-state firing rate (in Hz), from the mean inter-spike
+    /// interval in the second half of the step. 0 if the neuron stops firing.
+    pub fn steady_state_rate(&self) -> Num {
+        let half = self.duration / 2.0;
+        let late: Vec<Num> = self
+            .spike_times
+            .windows(2)
+            .filter(|w| w[1] > half)
+            .map(|w| w[1] - w[0])
+            .collect();
+        if late.is_empty() {
+            return 0.0;
+        }
+        let last_spike = *self.spike_times.last().unwrap();
+        let mean_interval = late.iter().sum::<Num>() / late.len() as Num;
+        if self.duration - last_spike > 2.0 * mean_interval {
+            // the neuron stopped firing
+            return 0.0;
+        }
+        1000.0 / mean_interval
+    }
+
+    /// The adaptation index: the mean of `(isi[n + 1] - isi[n]) / (isi[n + 1] + isi[n])`
+    /// over consecutive inter-spike intervals. Positive for adapting,
+    /// negative for accelerating neurons. `None` with less than three spikes.
+    pub fn adaptation_index(&self) -> Option<Num> {
+        let intervals = self.intervals();
+        if intervals.len() < 2 {
+            return None;
+        }
+        let sum: Num = intervals
+            .windows(2)
+            .map(|w| (w[1] - w[0]) / (w[1] + w[0]))
+            .sum();
+        Some(sum / (intervals.len() - 1) as Num)
+    }
+
+    /// The behaviour of the neuron.
+    pub fn firing_pattern(&self) -> FiringPattern {
+        FiringPattern::classify(&self.spike_times, 0.0, self.duration)
+    }
+
+    pub fn burst_statistics(&self) -> BurstStatistics {
+        BurstStatistics::of(&self.spike_times)
+    }
+}
+
+/// The step responses to `n` currents evenly spaced from `min_current` to
+/// `max_current`, each lasting `duration` ms. The steady-state rates form
+/// the f-I curve.
+pub fn fi_curve(
+    config: &NeuronConfig,
+    (min_current, max_current): (Num, Num),
+    n: usize,
+    duration: Num,
+) -> Vec<StepResponse> {
+    (0..n)
+        .map(|i| {
+            let current = if n > 1 {
+                min_current + (max_current - min_current) * i as Num / (n - 1) as Num
+            } else {
+                min_current
+            };
+            StepResponse::measure(config, current, duration)
+        })
+        .collect()
+}
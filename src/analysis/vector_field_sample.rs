@@ -0,0 +1,14 @@
+use crate::Num;
+
+/// The direction of motion at a point `(v, u)` of the phase plane.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct VectorFieldSample {
+    pub v: Num,
+    pub u: Num,
+
+    /// `dv/dt` (in mV/ms)
+    pub dv: Num,
+
+    /// `du/dt` (per ms)
+    pub du: Num,
+}
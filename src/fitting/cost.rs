@@ -0,0 +1,100 @@
+use crate::fitting::Recording;
+use crate::model::NeuronConfig;
+use crate::Num;
+
+/// How well a neuron reproduces a `Recording`. Lower is better.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Cost {
+    /// Root mean square error of the membrane potential (in mV). Small
+    /// shifts of spikes cause large errors, so this cost has many local
+    /// minima for spiking traces and needs a good initial guess.
+    VoltageRmse,
+
+    /// `1 - gamma`, where `gamma` is the Gamma factor of spike coincidences
+    /// within `delta` ms (see `gamma_factor`).
+    GammaFactor { delta: Num },
+}
+
+impl Cost {
+    /// The cost of `config` on `recording`.
+    pub fn evaluate(&self, config: &NeuronConfig, recording: &Recording) -> Num {
+        let (voltage, spike_times) = recording.simulate(config);
+        let cost = match *self {
+            Cost::VoltageRmse => voltage_rmse(&voltage, &recording.voltage),
+            Cost::GammaFactor { delta } => {
+                1.0 - gamma_factor(
+                    &spike_times,
+                    &recording.spike_times,
+                    delta,
+                    recording.duration(),
+                )
+            }
+        };
+        if cost.is_nan() {
+            Num::INFINITY
+        } else {
+            cost
+        }
+    }
+}
+
+/// The root mean square difference of two voltage traces. Infinite if
+/// their lengths differ, e.g. for a `Recording` of spike times only.
+pub fn voltage_rmse(model: &[Num], data: &[Num]) -> Num {
+    if model.len() != data.len() {
+        return Num::INFINITY;
+    }
+    if data.is_empty() {
+        return 0.0;
+    }
+    let sum: Num = model
+        .iter()
+        .zip(data.iter())
+        .map(|(m, d)| (m - d) * (m - d))
+        .sum();
+    (sum / data.len() as Num).sqrt()
+}
+
+/// The number of spikes of `data` which have a spike of `model` within
+/// `delta` ms. Each model spike is matched at most once. Both lists must be
+/// sorted.
+fn coincidences(model: &[Num], data: &[Num], delta: Num) -> usize {
+    let mut count = 0;
+    let mut j = 0;
+    for &t in data {
+        while j < model.len() && model[j] < t - delta {
+            j += 1;
+        }
+        if j < model.len() && model[j] <= t + delta {
+            count += 1;
+            j += 1;
+        }
+    }
+    count
+}
+
+/// The Gamma factor of Kistler et al. (1997): the number of coincidences
+/// within `delta` ms of the spike trains `model` and `data` over a
+/// recording of `duration` ms, corrected for the coincidences expected by
+/// chance and normalised, so that it is 1 for identical and about 0 for
+/// independent spike trains.
+///
+/// The normalisation uses the rate of `data` rather than that of `model`,
+/// so that it stays positive for models firing at very high rates. NaN if
+/// `data` fire more than once per `2 * delta` ms, which `Cost::evaluate`
+/// treats as an infinite cost.
+pub fn gamma_factor(model: &[Num], data: &[Num], delta: Num, duration: Num) -> Num {
+    if model.is_empty() && data.is_empty() {
+        return 1.0;
+    }
+    let n_model = model.len() as Num;
+    let n_data = data.len() as Num;
+    let expected = 2.0 * delta * n_model / duration * n_data;
+    let normalisation = 1.0 - 2.0 * delta * n_data / duration;
+    if normalisation <= 0.0 {
+        // data fire too fast for delta
+        return Num::NAN;
+    }
+    let n_coincidences = coincidences(model, data, delta) as Num;
+    (n_coincidences - expected) / (0.5 * (n_model + n_data)) / normalisation
+}
@@ -0,0 +1,41 @@
+use crate::fitting::{Cost, FitResult, NelderMead, Recording};
+use crate::model::NeuronConfig;
+use crate::Num;
+
+/// Initial simplex step of the parameters `a`, `b`, `c` and `d`.
+const STEP: [Num; 4] = [0.01, 0.05, 5.0, 2.0];
+
+/// `initial` with the parameters `[a, b, c, d]`.
+fn with_parameters(initial: &NeuronConfig, x: &[Num]) -> NeuronConfig {
+    NeuronConfig {
+        a: x[0],
+        b: x[1],
+        c: x[2],
+        d: x[3],
+        ..initial.clone()
+    }
+}
+
+/// Fits the parameters `a`, `b`, `c` and `d` of a neuron to `recording`,
+/// minimizing `cost` with `optimizer`, starting at `initial`. The membrane
+/// equation of `initial` is kept. The parameters are not constrained, as
+/// e.g. inhibition-induced spiking needs a negative `a`; configurations
+/// whose simulation diverges have an infinite cost.
+pub fn fit(
+    recording: &Recording,
+    cost: Cost,
+    initial: &NeuronConfig,
+    optimizer: &NelderMead,
+) -> FitResult {
+    let x0 = [initial.a, initial.b, initial.c, initial.d];
+    let (x, cost, iterations) = optimizer.minimize(
+        |x| cost.evaluate(&with_parameters(initial, x), recording),
+        &x0,
+        &STEP,
+    );
+    FitResult {
+        config: with_parameters(initial, &x),
+        cost,
+        iterations,
+    }
+}
@@ -0,0 +1,15 @@
+use crate::model::NeuronConfig;
+use crate::Num;
+
+/// The outcome of `fit`.
+#[derive(Debug, Clone)]
+pub struct FitResult {
+    /// The best configuration found.
+    pub config: NeuronConfig,
+
+    /// Its cost.
+    pub cost: Num,
+
+    /// Number of iterations of the optimiser.
+    pub iterations: usize,
+}
@@ -0,0 +1,11 @@
+pub mod cost;
+pub mod fit;
+pub mod fit_result;
+pub mod nelder_mead;
+pub mod recording;
+
+pub use cost::{gamma_factor, voltage_rmse, Cost};
+pub use fit::fit;
+pub use fit_result::FitResult;
+pub use nelder_mead::NelderMead;
+pub use recording::Recording;
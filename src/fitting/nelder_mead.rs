@@ -0,0 +1,154 @@
+use crate::Num;
+
+/// The derivative-free downhill simplex method of Nelder and Mead (1965).
+#[derive(Debug, Copy, Clone)]
+pub struct NelderMead {
+    /// Maximum number of iterations.
+    pub max_iterations: usize,
+
+    /// The search stops when the costs of all vertices of the simplex
+    /// differ by less than `tolerance`, or when the simplex has shrunk to
+    /// `tolerance` times the initial step along every axis.
+    pub tolerance: Num,
+
+    /// How often the search is restarted from the best point with a fresh
+    /// simplex, which helps to escape from plateaus of the cost function.
+    pub restarts: usize,
+}
+
+impl Default for NelderMead {
+    fn default() -> Self {
+        NelderMead {
+            max_iterations: 1000,
+            tolerance: 1.0e-6,
+            restarts: 2,
+        }
+    }
+}
+
+const REFLECTION: Num = 1.0;
+const EXPANSION: Num = 2.0;
+const CONTRACTION: Num = 0.5;
+const SHRINK: Num = 0.5;
+
+/// `a + factor * (b - a)`
+fn towards(a: &[Num], b: &[Num], factor: Num) -> Vec<Num> {
+    a.iter()
+        .zip(b.iter())
+        .map(|(a, b)| a + factor * (b - a))
+        .collect()
+}
+
+impl NelderMead {
+    /// Minimizes `f` starting from the simplex spanned by `x0` and `x0`
+    /// moved by `step[i]` along each axis `i`. Returns the best point found,
+    /// its cost and the number of iterations.
+    pub fn minimize<F>(&self, mut f: F, x0: &[Num], step: &[Num]) -> (Vec<Num>, Num, usize)
+    where
+        F: FnMut(&[Num]) -> Num,
+    {
+        let (mut x, mut cost, mut iterations) = self.search(&mut f, x0, step, self.max_iterations);
+        for _ in 0..self.restarts {
+            if iterations >= self.max_iterations {
+                break;
+            }
+            let (new_x, new_cost, new_iterations) =
+                self.search(&mut f, &x, step, self.max_iterations - iterations);
+            iterations += new_iterations;
+            if new_cost >= cost {
+                break;
+            }
+            x = new_x;
+            cost = new_cost;
+        }
+        (x, cost, iterations)
+    }
+
+    /// A single search of at most `max_iterations`.
+    fn search<F>(
+        &self,
+        f: &mut F,
+        x0: &[Num],
+        step: &[Num],
+        max_iterations: usize,
+    ) -> (Vec<Num>, Num, usize)
+    where
+        F: FnMut(&[Num]) -> Num,
+    {
+        assert_eq!(x0.len(), step.len());
+        let n = x0.len();
+
+        let mut simplex: Vec<(Vec<Num>, Num)> = (0..=n)
+            .map(|i| {
+                let mut x = x0.to_vec();
+                if i > 0 {
+                    x[i - 1] += step[i - 1];
+                }
+                let cost = f(&x);
+                (x, cost)
+            })
+            .collect();
+
+        let mut iterations = 0;
+        while iterations < max_iterations {
+            simplex.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+            let size = simplex[1..]
+                .iter()
+                .flat_map(|(x, _)| {
+                    x.iter()
+                        .zip(simplex[0].0.iter())
+                        .zip(step.iter())
+                        .map(|((x, best), step)| ((x - best) / step).abs())
+                })
+                .fold(0.0, Num::max);
+            if (simplex[n].1 - simplex[0].1).abs() < self.tolerance || size < self.tolerance {
+                break;
+            }
+            iterations += 1;
+
+            let mut centroid = vec![0.0; n];
+            for (x, _) in simplex[..n].iter() {
+                for (c, xi) in centroid.iter_mut().zip(x.iter()) {
+                    *c += xi / n as Num;
+                }
+            }
+
+            let worst = simplex[n].clone();
+            let reflected = towards(&centroid, &worst.0, -REFLECTION);
+            let reflected_cost = f(&reflected);
+
+            if reflected_cost < simplex[0].1 {
+                let expanded = towards(&centroid, &worst.0, -EXPANSION);
+                let expanded_cost = f(&expanded);
+                simplex[n] = if expanded_cost < reflected_cost {
+                    (expanded, expanded_cost)
+                } else {
+                    (reflected, reflected_cost)
+                };
+            } else if reflected_cost < simplex[n - 1].1 {
+                simplex[n] = (reflected, reflected_cost);
+            } else {
+                let contracted = if reflected_cost < worst.1 {
+                    towards(&centroid, &reflected, CONTRACTION)
+                } else {
+                    towards(&centroid, &worst.0, CONTRACTION)
+                };
+                let contracted_cost = f(&contracted);
+                if contracted_cost < worst.1.min(reflected_cost) {
+                    simplex[n] = (contracted, contracted_cost);
+                } else {
+                    let best = simplex[0].0.clone();
+                    for vertex in simplex[1..].iter_mut() {
+                        let x = towards(&best, &vertex.0, SHRINK);
+                        let cost = f(&x);
+                        *vertex = (x, cost);
+                    }
+                }
+            }
+        }
+
+        simplex.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+        let (x, cost) = simplex.swap_remove(0);
+        (x, cost, iterations)
+    }
+}
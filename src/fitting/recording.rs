@@ -0,0 +1,85 @@
+use crate::model::{Integrator, NeuronConfig, NeuronState};
+use crate::Num;
+
+/// A recorded experiment a neuron is fitted to: the injected current,
+/// sampled every `dt` ms, and the recorded response.
+#[derive(Debug, Clone)]
+pub struct Recording {
+    /// Sampling interval (in ms).
+    pub dt: Num,
+
+    /// The injected current of every sample.
+    pub current: Vec<Num>,
+
+    /// The membrane potential of every sample (in mV). May be empty if only
+    /// spike times were recorded.
+    pub voltage: Vec<Num>,
+
+    /// The spike times (in ms).
+    pub spike_times: Vec<Num>,
+
+    /// The membrane potential at `t = 0` (in mV). The recovery variable
+    /// starts at `b * v0`.
+    pub v0: Num,
+}
+
+impl Recording {
+    /// A recording of `voltage` in response to `current`, with the spikes
+    /// detected as upward crossings of `spike_threshold`.
+    pub fn from_voltage(
+        dt: Num,
+        current: Vec<Num>,
+        voltage: Vec<Num>,
+        spike_threshold: Num,
+    ) -> Self {
+        assert_eq!(current.len(), voltage.len());
+        let spike_times = voltage
+            .windows(2)
+            .enumerate()
+            .filter(|(_, w)| w[0] < spike_threshold && w[1] >= spike_threshold)
+            .map(|(i, _)| (i + 1) as Num * dt)
+            .collect();
+        let v0 = voltage.first().copied().unwrap_or(-70.0);
+        Recording {
+            dt,
+            current,
+            voltage,
+            spike_times,
+            v0,
+        }
+    }
+
+    /// A recording of `spike_times` in response to `current`.
+    pub fn from_spike_times(dt: Num, current: Vec<Num>, spike_times: Vec<Num>, v0: Num) -> Self {
+        Recording {
+            dt,
+            current,
+            voltage: Vec::new(),
+            spike_times,
+            v0,
+        }
+    }
+
+    /// Duration of the recording (in ms).
+    pub fn duration(&self) -> Num {
+        self.current.len() as Num * self.dt
+    }
+
+    /// Replays the injected current to a neuron configured with `config`.
+    /// Returns its potential at every sample and its spike times.
+    pub fn simulate(&self, config: &NeuronConfig) -> (Vec<Num>, Vec<Num>) {
+        let integrator = Integrator::default();
+        let mut state = NeuronState::with(self.v0, config.b * self.v0);
+        let mut voltage = Vec::with_capacity(self.current.len());
+        let mut spike_times = Vec::new();
+        for (i, &current) in self.current.iter().enumerate() {
+            voltage.push(state.potential());
+            let (new_state, activity) = state.integrate(integrator, self.dt, current, config);
+            state = new_state;
+            if activity.fires() {
+                spike_times.push((i + 1) as Num * self.dt);
+            }
+        }
+        (voltage, spike_times)
+    }
+}
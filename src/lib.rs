@@ -1,11 +1,22 @@
 /// We use this numerical type for all neuron model parameters (e.g. potential).
+///
+/// Single precision by default. Enable the `f64` feature to use double
+/// precision, e.g. for validating long runs of STDP weight accumulation.
+#[cfg(not(feature = "f64"))]
 pub type Num = f32;
 
+/// We use this numerical type for all neuron model parameters (e.g. potential).
+///
+/// Double precision, as the `f64` feature is enabled.
+#[cfg(feature = "f64")]
+pub type Num = f64;
+
+#[derive(Debug, Copy, Clone)]
 pub struct Closed01(Num);
 
 impl Closed01 {
     pub fn new(f: Num) -> Self {
-        assert!(f >= 0.0 && f <= 1.0);
+        assert!((0.0..=1.0).contains(&f));
         Self(f)
     }
     pub fn get(self) -> Num {
@@ -13,6 +24,9 @@ impl Closed01 {
     }
 }
 
+pub mod analysis;
+pub mod fitting;
 pub mod model;
 pub mod network;
+pub mod plasticity;
 pub mod simulation;
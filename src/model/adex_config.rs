@@ -0,0 +1,160 @@
+use crate::model::{Integrator, NeuronModel, OdeSystem};
+use crate::Num;
+
+/// Parameters of the adaptive exponential integrate-and-fire (AdEx) neuron
+/// of Brette and Gerstner (2005):
+///
+/// `C * dv/dt = -g_l * (v - e_l) + g_l * delta_t * exp((v - v_t) / delta_t) - w + I`
+///
+/// `tau_w * dw/dt = a * (v - e_l) - w`
+///
+/// Once `v` reaches `v_peak`, the neuron fires, `v` is reset to `v_reset`
+/// and `w` is increased by `b`. Currents are in pA.
+#[derive(Debug, Clone)]
+pub struct AdExConfig {
+    /// Membrane capacitance (in pF).
+    pub c_m: Num,
+
+    /// Leak conductance (in nS).
+    pub g_l: Num,
+
+    /// Leak reversal potential (in mV).
+    pub e_l: Num,
+
+    /// Threshold potential (in mV).
+    pub v_t: Num,
+
+    /// Slope factor (in mV).
+    pub delta_t: Num,
+
+    /// Adaptation time constant (in ms).
+    pub tau_w: Num,
+
+    /// Subthreshold adaptation (in nS).
+    pub a: Num,
+
+    /// Spike-triggered adaptation (in pA).
+    pub b: Num,
+
+    /// After-spike reset value of the membrane potential (in mV).
+    pub v_reset: Num,
+
+    /// Potential at which a spike is detected (in mV).
+    pub v_peak: Num,
+}
+
+impl Default for AdExConfig {
+    /// The tonic spiking parameters of Brette and Gerstner (2005).
+    fn default() -> Self {
+        AdExConfig {
+            c_m: 281.0,
+            g_l: 30.0,
+            e_l: -70.6,
+            v_t: -50.4,
+            delta_t: 2.0,
+            tau_w: 144.0,
+            a: 4.0,
+            b: 80.5,
+            v_reset: -70.6,
+            v_peak: 20.0,
+        }
+    }
+}
+
+/// State of an AdEx neuron.
+#[derive(Copy, Clone, Debug)]
+pub struct AdExState {
+    /// membrane potential of neuron (in mV)
+    pub v: Num,
+
+    /// adaptation current (in pA)
+    pub w: Num,
+}
+
+struct Dynamics<'a> {
+    config: &'a AdExConfig,
+    i_syn: Num,
+}
+
+impl<'a> Dynamics<'a> {
+    #[inline(always)]
+    fn exponential(&self, v: Num) -> Num {
+        let c = self.config;
+        ((v - c.v_t) / c.delta_t).exp()
+    }
+}
+
+impl<'a> OdeSystem<2> for Dynamics<'a> {
+    fn derivative(&self, &[v, w]: &[Num; 2]) -> [Num; 2] {
+        let c = self.config;
+        // The potential diverges past the threshold. Intermediate stages of
+        // an integrator may evaluate it beyond the peak, which must neither
+        // overflow nor drive the adaptation current.
+        let v = v.min(c.v_peak);
+        let dv = (-c.g_l * (v - c.e_l) + c.g_l * c.delta_t * self.exponential(v) - w + self.i_syn)
+            / c.c_m;
+        let dw = (c.a * (v - c.e_l) - w) / c.tau_w;
+        [dv, dw]
+    }
+
+    fn diagonal_jacobian(&self, &[v, _]: &[Num; 2]) -> [Num; 2] {
+        let c = self.config;
+        let v = v.min(c.v_peak);
+        [c.g_l * (self.exponential(v) - 1.0) / c.c_m, -1.0 / c.tau_w]
+    }
+
+    fn escaped(&self, &[v, _]: &[Num; 2]) -> bool {
+        v >= self.config.v_peak
+    }
+}
+
+impl NeuronModel for AdExConfig {
+    type State = AdExState;
+
+    fn initial_state(&self) -> AdExState {
+        AdExState {
+            v: self.e_l,
+            w: 0.0,
+        }
+    }
+
+    fn potential(&self, state: &AdExState) -> Num {
+        state.v.min(self.v_peak)
+    }
+
+    fn step(&self, state: AdExState, i_syn: Num, dt: Num, integrator: Integrator) -> AdExState {
+        let dynamics = Dynamics {
+            config: self,
+            i_syn,
+        };
+        let [v, w] = integrator.integrate(&dynamics, [state.v, state.w], dt);
+        AdExState { v, w }
+    }
+
+    fn spike_condition(&self, _previous: &AdExState, state: &AdExState) -> bool {
+        state.v >= self.v_peak
+    }
+
+    fn reset(&self, state: AdExState) -> AdExState {
+        AdExState {
+            v: self.v_reset,
+            w: state.w + self.b,
+        }
+    }
+
+    fn has_threshold(&self) -> bool {
+        true
+    }
+
+    fn shift_threshold(&mut self, delta: Num) {
+        self.v_t += delta;
+    }
+
+    fn has_adaptation(&self) -> bool {
+        true
+    }
+
+    fn increase_adaptation(&mut self, delta: Num) {
+        self.b = (self.b + delta).max(0.0);
+    }
+}
@@ -0,0 +1,70 @@
+use crate::Num;
+
+/// The time course of the current a `Receptor::Current` synapse injects
+/// into its post-synaptic neuron after a spike arrives.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub enum CurrentKernel {
+    /// The weight is injected as a current pulse during a single time step.
+    #[default]
+    Pulse,
+
+    /// The current jumps to the weight and decays with time constant `tau` (in ms).
+    Exponential { tau: Num },
+
+    /// The current follows the alpha function `t / tau * exp(1 - t / tau)`,
+    /// which peaks at the weight `tau` ms after the spike arrived.
+    Alpha { tau: Num },
+}
+
+/// The summed post-synaptic current of all synapses with the same kernel
+/// onto a neuron.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct KernelCurrent {
+    pub(crate) kernel: CurrentKernel,
+
+    /// the current
+    i: Num,
+
+    /// the rise of an alpha kernel
+    y: Num,
+}
+
+impl KernelCurrent {
+    pub(crate) fn new(kernel: CurrentKernel) -> Self {
+        assert!(kernel != CurrentKernel::Pulse);
+        KernelCurrent {
+            kernel,
+            i: 0.0,
+            y: 0.0,
+        }
+    }
+
+    /// A spike of `weight` arrived.
+    #[inline]
+    pub(crate) fn excite(&mut self, weight: Num) {
+        match self.kernel {
+            CurrentKernel::Pulse => unreachable!(),
+            CurrentKernel::Exponential { .. } => self.i += weight,
+            CurrentKernel::Alpha { tau } => self.y += weight * std::f64::consts::E as Num / tau,
+        }
+    }
+
+    /// The current during the next time step. Advances the kernel by `dt` ms
+    /// using its exact solution.
+    #[inline]
+    pub(crate) fn current(&mut self, dt: Num) -> Num {
+        let current = self.i;
+        match self.kernel {
+            CurrentKernel::Pulse => unreachable!(),
+            CurrentKernel::Exponential { tau } => {
+                self.i *= (-dt / tau).exp();
+            }
+            CurrentKernel::Alpha { tau } => {
+                let p = (-dt / tau).exp();
+                self.i = p * (self.i + dt * self.y);
+                self.y *= p;
+            }
+        }
+        current
+    }
+}
@@ -0,0 +1,167 @@
+use crate::model::{Integrator, NeuronModel, OdeSystem};
+use crate::Num;
+
+/// Parameters of the Hodgkin-Huxley model of the squid giant axon, with
+/// the potential shifted to a resting potential of -65 mV:
+///
+/// `C * dv/dt = -g_na * m^3 * h * (v - e_na) - g_k * n^4 * (v - e_k) - g_l * (v - e_l) + I`
+///
+/// The action potential is generated by the model itself; a spike is
+/// detected when `v` crosses `v_spike` from below. Currents are in uA/cm^2.
+///
+/// The gating variables are stiff, so this model should be simulated with
+/// `Integrator::ExponentialEuler` or small time steps.
+#[derive(Debug, Clone)]
+pub struct HodgkinHuxleyConfig {
+    /// Membrane capacitance (in uF/cm^2).
+    pub c_m: Num,
+
+    /// Maximum sodium conductance (in mS/cm^2).
+    pub g_na: Num,
+
+    /// Maximum potassium conductance (in mS/cm^2).
+    pub g_k: Num,
+
+    /// Leak conductance (in mS/cm^2).
+    pub g_l: Num,
+
+    /// Sodium reversal potential (in mV).
+    pub e_na: Num,
+
+    /// Potassium reversal potential (in mV).
+    pub e_k: Num,
+
+    /// Leak reversal potential (in mV).
+    pub e_l: Num,
+
+    /// Upward crossings of this potential are reported as spikes (in mV).
+    pub v_spike: Num,
+}
+
+impl Default for HodgkinHuxleyConfig {
+    fn default() -> Self {
+        HodgkinHuxleyConfig {
+            c_m: 1.0,
+            g_na: 120.0,
+            g_k: 36.0,
+            g_l: 0.3,
+            e_na: 50.0,
+            e_k: -77.0,
+            e_l: -54.387,
+            v_spike: 0.0,
+        }
+    }
+}
+
+/// State of a Hodgkin-Huxley neuron.
+#[derive(Copy, Clone, Debug)]
+pub struct HodgkinHuxleyState {
+    /// membrane potential of neuron (in mV)
+    pub v: Num,
+
+    /// sodium activation
+    pub m: Num,
+
+    /// sodium inactivation
+    pub h: Num,
+
+    /// potassium activation
+    pub n: Num,
+}
+
+/// `x / (1 - exp(-x / y))`, which has a removable singularity at `x = 0`.
+#[inline(always)]
+fn vtrap(x: Num, y: Num) -> Num {
+    if (x / y).abs() < 1.0e-6 {
+        y * (1.0 + x / y / 2.0)
+    } else {
+        x / (1.0 - (-x / y).exp())
+    }
+}
+
+/// The opening (alpha) and closing (beta) rates of the gates m, h and n.
+#[inline(always)]
+fn rates(v: Num) -> [(Num, Num); 3] {
+    [
+        (
+            0.1 * vtrap(v + 40.0, 10.0),
+            4.0 * (-(v + 65.0) / 18.0).exp(),
+        ),
+        (
+            0.07 * (-(v + 65.0) / 20.0).exp(),
+            1.0 / (1.0 + (-(v + 35.0) / 10.0).exp()),
+        ),
+        (
+            0.01 * vtrap(v + 55.0, 10.0),
+            0.125 * (-(v + 65.0) / 80.0).exp(),
+        ),
+    ]
+}
+
+struct Dynamics<'a> {
+    config: &'a HodgkinHuxleyConfig,
+    i_syn: Num,
+}
+
+impl<'a> OdeSystem<4> for Dynamics<'a> {
+    fn derivative(&self, &[v, m, h, n]: &[Num; 4]) -> [Num; 4] {
+        let c = self.config;
+        let i_ion = c.g_na * m * m * m * h * (v - c.e_na)
+            + c.g_k * n * n * n * n * (v - c.e_k)
+            + c.g_l * (v - c.e_l);
+        let [(am, bm), (ah, bh), (an, bn)] = rates(v);
+        [
+            (self.i_syn - i_ion) / c.c_m,
+            am * (1.0 - m) - bm * m,
+            ah * (1.0 - h) - bh * h,
+            an * (1.0 - n) - bn * n,
+        ]
+    }
+
+    fn diagonal_jacobian(&self, &[v, m, h, n]: &[Num; 4]) -> [Num; 4] {
+        let c = self.config;
+        let g = c.g_na * m * m * m * h + c.g_k * n * n * n * n + c.g_l;
+        let [(am, bm), (ah, bh), (an, bn)] = rates(v);
+        [-g / c.c_m, -(am + bm), -(ah + bh), -(an + bn)]
+    }
+}
+
+impl NeuronModel for HodgkinHuxleyConfig {
+    type State = HodgkinHuxleyState;
+
+    /// The resting state, with all gates at their steady state.
+    fn initial_state(&self) -> HodgkinHuxleyState {
+        let v = -65.0;
+        let [(am, bm), (ah, bh), (an, bn)] = rates(v);
+        HodgkinHuxleyState {
+            v,
+            m: am / (am + bm),
+            h: ah / (ah + bh),
+            n: an / (an + bn),
+        }
+    }
+
+    fn potential(&self, state: &HodgkinHuxleyState) -> Num {
+        state.v
+    }
+
+    fn step(
+        &self,
+        state: HodgkinHuxleyState,
+        i_syn: Num,
+        dt: Num,
+        integrator: Integrator,
+    ) -> HodgkinHuxleyState {
+        let dynamics = Dynamics {
+            config: self,
+            i_syn,
+        };
+        let y = [state.v, state.m, state.h, state.n];
+        let [v, m, h, n] = integrator.integrate(&dynamics, y, dt);
+        HodgkinHuxleyState { v, m, h, n }
+    }
+
+    fn spike_condition(&self, previous: &HodgkinHuxleyState, state: &HodgkinHuxleyState) -> bool {
+        previous.v < self.v_spike && state.v >= self.v_spike
+    }
+}
@@ -0,0 +1,261 @@
+use crate::Num;
+
+/// A system of ordinary differential equations `dy/dt = f(y)` with `N`
+/// state variables, which can be solved by an `Integrator`.
+pub trait OdeSystem<const N: usize> {
+    /// Evaluates `f(y)`.
+    fn derivative(&self, y: &[Num; N]) -> [Num; N];
+
+    /// The diagonal of the Jacobian of `f`, i.e. `df_i/dy_i`. Used by the
+    /// exponential Euler method.
+    fn diagonal_jacobian(&self, y: &[Num; N]) -> [Num; N];
+
+    /// Returns true once `y` has left the region in which the system is
+    /// meant to be integrated (e.g. when a neuron's potential has crossed
+    /// its spike threshold). Integrators stop at the end of the (sub)step
+    /// at which this happens instead of following the divergent trajectory.
+    fn escaped(&self, _y: &[Num; N]) -> bool {
+        false
+    }
+}
+
+/// Numerical integration method used to advance a neuron's state.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Integrator {
+    /// Forward Euler split into `substeps` equally sized steps.
+    ForwardEuler { substeps: usize },
+
+    /// The classical fourth order Runge-Kutta method with `substeps`
+    /// equally sized steps.
+    RungeKutta4 { substeps: usize },
+
+    /// Exponential Euler with `substeps` equally sized steps. Integrates
+    /// the linear part of each equation exactly, which makes it well
+    /// suited for stiff systems.
+    ExponentialEuler { substeps: usize },
+
+    /// Dormand-Prince Runge-Kutta 4(5) with adaptive step size control.
+    /// `tolerance` is used as both absolute and relative error tolerance.
+    /// Panics if the state becomes non-finite even at the smallest step.
+    AdaptiveRk45 { tolerance: Num },
+}
+
+impl Default for Integrator {
+    /// Two forward Euler half-steps per millisecond, as used by Izhikevich.
+    fn default() -> Self {
+        Integrator::ForwardEuler { substeps: 2 }
+    }
+}
+
+/// The smallest step (relative to `dt`) the adaptive integrator will take.
+const MIN_RELATIVE_STEP: Num = 1.0e-6;
+
+impl Integrator {
+    /// Advances `y` by `dt` according to `system`.
+    pub fn integrate<S, const N: usize>(&self, system: &S, y: [Num; N], dt: Num) -> [Num; N]
+    where
+        S: OdeSystem<N>,
+    {
+        match *self {
+            Integrator::ForwardEuler { substeps } => {
+                fixed_steps(system, y, dt, substeps, forward_euler)
+            }
+            Integrator::RungeKutta4 { substeps } => {
+                fixed_steps(system, y, dt, substeps, runge_kutta4)
+            }
+            Integrator::ExponentialEuler { substeps } => {
+                fixed_steps(system, y, dt, substeps, exponential_euler)
+            }
+            Integrator::AdaptiveRk45 { tolerance } => adaptive_rk45(system, y, dt, tolerance),
+        }
+    }
+}
+
+/// `y + h * dy`
+#[inline(always)]
+fn add<const N: usize>(y: &[Num; N], h: Num, dy: &[Num; N]) -> [Num; N] {
+    let mut r = *y;
+    for i in 0..N {
+        r[i] += h * dy[i];
+    }
+    r
+}
+
+/// `y + h * sum(coeffs[j] * ks[j])`
+#[inline(always)]
+fn combine<const N: usize>(y: &[Num; N], h: Num, coeffs: &[Num], ks: &[[Num; N]]) -> [Num; N] {
+    let mut r = *y;
+    for (c, k) in coeffs.iter().zip(ks) {
+        for i in 0..N {
+            r[i] += h * c * k[i];
+        }
+    }
+    r
+}
+
+/// Integrates `y` over `dt` by `substeps` steps of `method`.
+fn fixed_steps<S, const N: usize>(
+    system: &S,
+    mut y: [Num; N],
+    dt: Num,
+    substeps: usize,
+    method: fn(&S, [Num; N], Num) -> [Num; N],
+) -> [Num; N]
+where
+    S: OdeSystem<N>,
+{
+    assert!(substeps > 0);
+    let h = dt / substeps as Num;
+    for _ in 0..substeps {
+        if system.escaped(&y) {
+            break;
+        }
+        y = method(system, y, h);
+    }
+    y
+}
+
+fn forward_euler<S, const N: usize>(system: &S, y: [Num; N], h: Num) -> [Num; N]
+where
+    S: OdeSystem<N>,
+{
+    add(&y, h, &system.derivative(&y))
+}
+
+fn runge_kutta4<S, const N: usize>(system: &S, y: [Num; N], h: Num) -> [Num; N]
+where
+    S: OdeSystem<N>,
+{
+    let k1 = system.derivative(&y);
+    let k2 = system.derivative(&add(&y, h / 2.0, &k1));
+    let k3 = system.derivative(&add(&y, h / 2.0, &k2));
+    let k4 = system.derivative(&add(&y, h, &k3));
+    combine(&y, h / 6.0, &[1.0, 2.0, 2.0, 1.0], &[k1, k2, k3, k4])
+}
+
+fn exponential_euler<S, const N: usize>(system: &S, y: [Num; N], h: Num) -> [Num; N]
+where
+    S: OdeSystem<N>,
+{
+    let f = system.derivative(&y);
+    let jacobian = system.diagonal_jacobian(&y);
+    let mut r = y;
+    for i in 0..N {
+        // phi1(z) = (e^z - 1) / z, which tends to 1 for z -> 0.
+        let z = h * jacobian[i];
+        let phi1 = if z.abs() < 1.0e-6 {
+            1.0 + z / 2.0
+        } else {
+            z.exp_m1() / z
+        };
+        r[i] += h * phi1 * f[i];
+    }
+    r
+}
+
+// Butcher tableau of the Dormand-Prince method. As our systems are
+// autonomous, the nodes `c` are not needed.
+const DP_A: [&[Num]; 7] = [
+    &[],
+    &[1.0 / 5.0],
+    &[3.0 / 40.0, 9.0 / 40.0],
+    &[44.0 / 45.0, -56.0 / 15.0, 32.0 / 9.0],
+    &[
+        19372.0 / 6561.0,
+        -25360.0 / 2187.0,
+        64448.0 / 6561.0,
+        -212.0 / 729.0,
+    ],
+    &[
+        9017.0 / 3168.0,
+        -355.0 / 33.0,
+        46732.0 / 5247.0,
+        49.0 / 176.0,
+        -5103.0 / 18656.0,
+    ],
+    &[
+        35.0 / 384.0,
+        0.0,
+        500.0 / 1113.0,
+        125.0 / 192.0,
+        -2187.0 / 6784.0,
+        11.0 / 84.0,
+    ],
+];
+/// Weights of the 5th order solution (identical to the last row of `DP_A`).
+const DP_B5: [Num; 7] = [
+    35.0 / 384.0,
+    0.0,
+    500.0 / 1113.0,
+    125.0 / 192.0,
+    -2187.0 / 6784.0,
+    11.0 / 84.0,
+    0.0,
+];
+/// Weights of the embedded 4th order solution.
+const DP_B4: [Num; 7] = [
+    5179.0 / 57600.0,
+    0.0,
+    7571.0 / 16695.0,
+    393.0 / 640.0,
+    -92097.0 / 339200.0,
+    187.0 / 2100.0,
+    1.0 / 40.0,
+];
+
+fn adaptive_rk45<S, const N: usize>(
+    system: &S,
+    mut y: [Num; N],
+    dt: Num,
+    tolerance: Num,
+) -> [Num; N]
+where
+    S: OdeSystem<N>,
+{
+    assert!(tolerance > 0.0);
+    let min_step = dt * MIN_RELATIVE_STEP;
+    let mut t = 0.0;
+    let mut h = dt;
+
+    while t < dt && !system.escaped(&y) {
+        h = h.min(dt - t);
+
+        let mut ks = [[0.0; N]; 7];
+        for s in 0..7 {
+            ks[s] = system.derivative(&combine(&y, h, DP_A[s], &ks[..s]));
+        }
+        let y5 = combine(&y, h, &DP_B5, &ks);
+        let y4 = combine(&y, h, &DP_B4, &ks);
+
+        // Scaled maximum norm of the local error estimate.
+        let mut error: Num = 0.0;
+        for i in 0..N {
+            let scale = tolerance * (1.0 + y[i].abs().max(y5[i].abs()));
+            let e = (y5[i] - y4[i]).abs() / scale;
+            if e.is_nan() {
+                error = Num::INFINITY;
+                break;
+            }
+            error = error.max(e);
+        }
+
+        if error <= 1.0 || h <= min_step {
+            // a diverged system cannot be saved by smaller steps
+            assert!(
+                error.is_finite(),
+                "non-finite state at the minimum step size"
+            );
+            t += h;
+            y = y5;
+        }
+
+        let factor = if error > 0.0 {
+            0.9 * error.powf(-0.2)
+        } else {
+            5.0
+        };
+        h = (h * factor.clamp(0.2, 5.0)).max(min_step);
+    }
+
+    y
+}
@@ -0,0 +1,101 @@
+use crate::model::{Integrator, NeuronModel, OdeSystem};
+use crate::Num;
+
+/// Parameters of a leaky integrate-and-fire (LIF) neuron:
+///
+/// `tau_m * dv/dt = -(v - v_rest) + r_m * I`
+///
+/// Once `v` reaches `v_threshold`, the neuron fires and `v` is reset to `v_reset`.
+#[derive(Debug, Clone)]
+pub struct LifConfig {
+    /// Membrane time constant (in ms).
+    pub tau_m: Num,
+
+    /// Resting potential (in mV).
+    pub v_rest: Num,
+
+    /// After-spike reset value of the membrane potential (in mV).
+    pub v_reset: Num,
+
+    /// Firing threshold (in mV).
+    pub v_threshold: Num,
+
+    /// Membrane resistance, which scales the input current into mV.
+    pub r_m: Num,
+}
+
+impl Default for LifConfig {
+    fn default() -> Self {
+        LifConfig {
+            tau_m: 10.0,
+            v_rest: -65.0,
+            v_reset: -65.0,
+            v_threshold: -50.0,
+            r_m: 2.0,
+        }
+    }
+}
+
+/// State of a leaky integrate-and-fire neuron.
+#[derive(Copy, Clone, Debug)]
+pub struct LifState {
+    /// membrane potential of neuron (in mV)
+    pub v: Num,
+}
+
+struct Dynamics<'a> {
+    config: &'a LifConfig,
+    i_syn: Num,
+}
+
+impl<'a> OdeSystem<1> for Dynamics<'a> {
+    fn derivative(&self, &[v]: &[Num; 1]) -> [Num; 1] {
+        let c = self.config;
+        [(c.v_rest - v + c.r_m * self.i_syn) / c.tau_m]
+    }
+
+    fn diagonal_jacobian(&self, _y: &[Num; 1]) -> [Num; 1] {
+        [-1.0 / self.config.tau_m]
+    }
+
+    fn escaped(&self, &[v]: &[Num; 1]) -> bool {
+        v >= self.config.v_threshold
+    }
+}
+
+impl NeuronModel for LifConfig {
+    type State = LifState;
+
+    fn initial_state(&self) -> LifState {
+        LifState { v: self.v_rest }
+    }
+
+    fn potential(&self, state: &LifState) -> Num {
+        state.v
+    }
+
+    fn step(&self, state: LifState, i_syn: Num, dt: Num, integrator: Integrator) -> LifState {
+        let dynamics = Dynamics {
+            config: self,
+            i_syn,
+        };
+        let [v] = integrator.integrate(&dynamics, [state.v], dt);
+        LifState { v }
+    }
+
+    fn spike_condition(&self, _previous: &LifState, state: &LifState) -> bool {
+        state.v >= self.v_threshold
+    }
+
+    fn reset(&self, _state: LifState) -> LifState {
+        LifState { v: self.v_reset }
+    }
+
+    fn has_threshold(&self) -> bool {
+        true
+    }
+
+    fn shift_threshold(&mut self, delta: Num) {
+        self.v_threshold = (self.v_threshold + delta).max(self.v_reset);
+    }
+}
@@ -1,11 +1,34 @@
+pub mod adex_config;
+pub mod current_kernel;
+pub mod hodgkin_huxley_config;
+pub mod integrator;
+pub mod lif_config;
+pub mod multi_compartment_config;
 pub mod neuron_activity;
 pub mod neuron_config;
+pub mod neuron_model;
 pub mod neuron_state;
 pub mod neuron_type;
+pub mod receptor;
+pub mod short_term_plasticity;
+pub mod simple_model_config;
 pub mod stdp_config;
+pub mod stimulus_protocol;
 
+pub use adex_config::{AdExConfig, AdExState};
+pub use current_kernel::CurrentKernel;
+pub use hodgkin_huxley_config::{HodgkinHuxleyConfig, HodgkinHuxleyState};
+pub use integrator::{Integrator, OdeSystem};
+pub use lif_config::{LifConfig, LifState};
+pub use multi_compartment_config::{Dendrite, MultiCompartmentConfig, MultiCompartmentState};
 pub use neuron_activity::NeuronActivity;
 pub use neuron_config::NeuronConfig;
+pub use neuron_model::NeuronModel;
+pub(crate) use neuron_model::{Instance, ModelInstance};
 pub use neuron_state::NeuronState;
 pub use neuron_type::NeuronType;
+pub use receptor::Receptor;
+pub use short_term_plasticity::ShortTermPlasticity;
+pub use simple_model_config::{SimpleModelConfig, SimpleModelState};
 pub use stdp_config::StdpConfig;
+pub use stimulus_protocol::StimulusProtocol;
@@ -0,0 +1,197 @@
+use crate::model::{Integrator, NeuronActivity, NeuronModel, SimpleModelConfig, SimpleModelState};
+use crate::Num;
+
+/// A dendritic compartment of a `MultiCompartmentConfig`.
+#[derive(Debug, Clone)]
+pub struct Dendrite {
+    /// The dynamics of the compartment on its own.
+    pub config: SimpleModelConfig,
+
+    /// The compartment it is attached to: 0 for the soma, `k` for the
+    /// dendrite `k - 1`.
+    pub parent: usize,
+
+    /// Coupling conductance to the parent compartment (in nS).
+    pub conductance: Num,
+}
+
+impl Dendrite {
+    pub fn new(config: SimpleModelConfig, parent: usize, conductance: Num) -> Dendrite {
+        assert!(conductance >= 0.0);
+        Dendrite {
+            config,
+            parent,
+            conductance,
+        }
+    }
+}
+
+/// A neuron of the simple model with a soma and `N` dendritic compartments,
+/// as in Izhikevich, "Dynamical Systems in Neuroscience" (2007), chapter 8:
+///
+/// `C * dv/dt = k * (v - v_r) * (v - v_t) - u + I + sum(G * (v' - v))`
+///
+/// where the sum is over the compartments `v'` coupled to the compartment.
+/// Compartment 0 is the soma, compartment `k` the dendrite `k - 1`. The
+/// neuron fires when the soma does. A dendrite reaching its own `v_peak`
+/// generates a dendritic spike and is reset, without the neuron firing.
+///
+/// The coupling currents are computed from the potentials at the start of
+/// each step, so the conductances should stay well below `c_m / dt`.
+#[derive(Debug, Clone)]
+pub struct MultiCompartmentConfig<const N: usize> {
+    pub soma: SimpleModelConfig,
+    pub dendrites: [Dendrite; N],
+}
+
+/// State of a multi-compartment neuron.
+#[derive(Copy, Clone, Debug)]
+pub struct MultiCompartmentState<const N: usize> {
+    pub soma: SimpleModelState,
+    pub dendrites: [SimpleModelState; N],
+}
+
+impl<const N: usize> MultiCompartmentConfig<N> {
+    pub fn new(soma: SimpleModelConfig, dendrites: [Dendrite; N]) -> Self {
+        for (i, dendrite) in dendrites.iter().enumerate() {
+            // dendrites attach to the soma or to a preceding dendrite
+            assert!(dendrite.parent <= i);
+        }
+        MultiCompartmentConfig { soma, dendrites }
+    }
+
+    /// Integrates all compartments over `dt` ms. `i_syn[k]` is the input into
+    /// compartment `k`; missing entries are zero.
+    fn integrate(
+        &self,
+        state: MultiCompartmentState<N>,
+        i_syn: &[Num],
+        dt: Num,
+        integrator: Integrator,
+    ) -> MultiCompartmentState<N> {
+        let mut soma_current = i_syn.first().copied().unwrap_or(0.0);
+        let mut dendritic_current = [0.0; N];
+        for (k, i) in dendritic_current.iter_mut().enumerate() {
+            *i = i_syn.get(k + 1).copied().unwrap_or(0.0);
+        }
+
+        let potential = |compartment: usize| match compartment {
+            0 => state.soma.v,
+            k => state.dendrites[k - 1].v,
+        };
+        for (k, dendrite) in self.dendrites.iter().enumerate() {
+            let coupling = dendrite.conductance * (potential(dendrite.parent) - potential(k + 1));
+            dendritic_current[k] += coupling;
+            match dendrite.parent {
+                0 => soma_current -= coupling,
+                parent => dendritic_current[parent - 1] -= coupling,
+            }
+        }
+
+        let soma = self.soma.step(state.soma, soma_current, dt, integrator);
+        let mut dendrites = state.dendrites;
+        for ((dendrite, s), &i) in self
+            .dendrites
+            .iter()
+            .zip(dendrites.iter_mut())
+            .zip(dendritic_current.iter())
+        {
+            let new_state = dendrite.config.step(*s, i, dt, integrator);
+            *s = if dendrite.config.spike_condition(s, &new_state) {
+                dendrite.config.reset(new_state)
+            } else {
+                new_state
+            };
+        }
+        MultiCompartmentState { soma, dendrites }
+    }
+}
+
+impl<const N: usize> NeuronModel for MultiCompartmentConfig<N> {
+    type State = MultiCompartmentState<N>;
+
+    fn initial_state(&self) -> MultiCompartmentState<N> {
+        let mut dendrites = [self.soma.initial_state(); N];
+        for (s, dendrite) in dendrites.iter_mut().zip(self.dendrites.iter()) {
+            *s = dendrite.config.initial_state();
+        }
+        MultiCompartmentState {
+            soma: self.soma.initial_state(),
+            dendrites,
+        }
+    }
+
+    fn potential(&self, state: &MultiCompartmentState<N>) -> Num {
+        self.soma.potential(&state.soma)
+    }
+
+    fn step(
+        &self,
+        state: MultiCompartmentState<N>,
+        i_syn: Num,
+        dt: Num,
+        integrator: Integrator,
+    ) -> MultiCompartmentState<N> {
+        self.integrate(state, &[i_syn], dt, integrator)
+    }
+
+    fn spike_condition(
+        &self,
+        previous: &MultiCompartmentState<N>,
+        state: &MultiCompartmentState<N>,
+    ) -> bool {
+        self.soma.spike_condition(&previous.soma, &state.soma)
+    }
+
+    fn reset(&self, state: MultiCompartmentState<N>) -> MultiCompartmentState<N> {
+        MultiCompartmentState {
+            soma: self.soma.reset(state.soma),
+            dendrites: state.dendrites,
+        }
+    }
+
+    fn has_threshold(&self) -> bool {
+        self.soma.has_threshold()
+    }
+
+    fn shift_threshold(&mut self, delta: Num) {
+        self.soma.shift_threshold(delta);
+    }
+
+    fn has_adaptation(&self) -> bool {
+        self.soma.has_adaptation()
+    }
+
+    fn increase_adaptation(&mut self, delta: Num) {
+        self.soma.increase_adaptation(delta);
+    }
+
+    fn compartments(&self) -> usize {
+        N + 1
+    }
+
+    fn compartment_potential(&self, state: &MultiCompartmentState<N>, compartment: usize) -> Num {
+        match compartment {
+            0 => self.potential(state),
+            k => {
+                let dendrite = &self.dendrites[k - 1].config;
+                dendrite.potential(&state.dendrites[k - 1])
+            }
+        }
+    }
+
+    fn update_compartments(
+        &self,
+        state: MultiCompartmentState<N>,
+        i_syn: &[Num],
+        dt: Num,
+        integrator: Integrator,
+    ) -> (MultiCompartmentState<N>, NeuronActivity) {
+        let new_state = self.integrate(state, i_syn, dt, integrator);
+        if self.spike_condition(&state, &new_state) {
+            (self.reset(new_state), NeuronActivity::Fires)
+        } else {
+            (new_state, NeuronActivity::Silent)
+        }
+    }
+}
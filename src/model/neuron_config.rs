@@ -1,7 +1,8 @@
+use crate::model::{Integrator, NeuronActivity, NeuronModel, NeuronState};
 use crate::{Closed01, Num};
 
 /// A neuron's configuration parameters.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct NeuronConfig {
     /// Rate of recovery.
     pub(crate) a: Num,
@@ -14,39 +15,255 @@ pub struct NeuronConfig {
 
     /// After-spike reset of recovery variable `u`.
     pub d: Num,
+
+    /// Coefficients `[k2, k1, k0]` of the membrane equation
+    /// `dv/dt = k2 * v^2 + k1 * v + k0 - u + I`.
+    ///
+    /// This is `[0.04, 5, 140]` for all cells except class 1 excitable
+    /// and integrator cells of [2004].
+    pub(crate) membrane: [Num; 3],
+
+    /// If set, the recovery variable integrates `b * (v + 65)` instead of
+    /// relaxing towards `b * v`. Only used by the accommodation cell of [2004].
+    pub(crate) accommodation: bool,
 }
 
+/// Membrane equation coefficients of the standard model.
+const STANDARD_MEMBRANE: [Num; 3] = [0.04, 5.0, 140.0];
+
+/// Membrane equation coefficients used by class 1 excitable and integrator
+/// cells in [2004].
+const CLASS_1_MEMBRANE: [Num; 3] = [0.04, 4.1, 108.0];
+
 impl NeuronConfig {
+    /// Creates a configuration with the standard membrane equation
+    /// `dv/dt = 0.04 * v^2 + 5 * v + 140 - u + I`.
+    pub fn new(a: Num, b: Num, c: Num, d: Num) -> NeuronConfig {
+        NeuronConfig {
+            a,
+            b,
+            c,
+            d,
+            membrane: STANDARD_MEMBRANE,
+            accommodation: false,
+        }
+    }
+
     /// Generates an excitatory neuron configuration according to Izhikevich's paper [reentry]
     /// where `r` is a random variable uniformly distributed in [0, 1].
     pub fn excitatory(r: Closed01) -> NeuronConfig {
         let r = r.get();
         let r2 = r * r;
-        NeuronConfig {
-            a: 0.02,
-            b: 0.2,
-            c: -65.0 + 15.0 * r2,
-            d: 8.0 - 6.0 * r2,
-        }
+        NeuronConfig::new(0.02, 0.2, -65.0 + 15.0 * r2, 8.0 - 6.0 * r2)
     }
 
     pub fn inhibitory(r: Closed01) -> NeuronConfig {
         let r = r.get();
-        NeuronConfig {
-            a: 0.02 + 0.08 * r,
-            b: 0.25 - 0.05 * r,
-            c: -65.0,
-            d: 2.0,
-        }
+        NeuronConfig::new(0.02 + 0.08 * r, 0.25 - 0.05 * r, -65.0, 2.0)
     }
 
+    // Cortical and thalamic cell types of [2003] (Izhikevich, "Simple Model
+    // of Spiking Neurons", IEEE Transactions on Neural Networks, 2003).
+
     /// Regular spiking (RS) cell configuration.
     pub fn regular_spiking() -> NeuronConfig {
         NeuronConfig::excitatory(Closed01::new(0.0))
     }
 
+    /// Intrinsically bursting (IB) cell configuration.
+    pub fn intrinsically_bursting() -> NeuronConfig {
+        NeuronConfig::new(0.02, 0.2, -55.0, 4.0)
+    }
+
     /// Chattering (CH) cell configuration.
     pub fn chattering() -> NeuronConfig {
         NeuronConfig::excitatory(Closed01::new(1.0))
     }
+
+    /// Fast spiking (FS) cell configuration.
+    pub fn fast_spiking() -> NeuronConfig {
+        NeuronConfig::inhibitory(Closed01::new(1.0))
+    }
+
+    /// Low-threshold spiking (LTS) cell configuration.
+    pub fn low_threshold_spiking() -> NeuronConfig {
+        NeuronConfig::inhibitory(Closed01::new(0.0))
+    }
+
+    /// Thalamo-cortical (TC) cell configuration.
+    pub fn thalamo_cortical() -> NeuronConfig {
+        NeuronConfig::new(0.02, 0.25, -65.0, 0.05)
+    }
+
+    // Neuro-computational features of [2004] (Izhikevich, "Which Model to
+    // Use for Cortical Spiking Neurons?", IEEE Transactions on Neural
+    // Networks, 2004). The letters refer to the panels of figure 1.
+
+    /// (A) Tonic spiking.
+    pub fn tonic_spiking() -> NeuronConfig {
+        NeuronConfig::new(0.02, 0.2, -65.0, 6.0)
+    }
+
+    /// (B) Phasic spiking.
+    pub fn phasic_spiking() -> NeuronConfig {
+        NeuronConfig::new(0.02, 0.25, -65.0, 6.0)
+    }
+
+    /// (C) Tonic bursting.
+    pub fn tonic_bursting() -> NeuronConfig {
+        NeuronConfig::new(0.02, 0.2, -50.0, 2.0)
+    }
+
+    /// (D) Phasic bursting.
+    pub fn phasic_bursting() -> NeuronConfig {
+        NeuronConfig::new(0.02, 0.25, -55.0, 0.05)
+    }
+
+    /// (E) Mixed mode.
+    pub fn mixed_mode() -> NeuronConfig {
+        NeuronConfig::new(0.02, 0.2, -55.0, 4.0)
+    }
+
+    /// (F) Spike frequency adaptation.
+    pub fn spike_frequency_adaptation() -> NeuronConfig {
+        NeuronConfig::new(0.01, 0.2, -65.0, 8.0)
+    }
+
+    /// (G) Class 1 excitability.
+    pub fn class_1_excitable() -> NeuronConfig {
+        NeuronConfig {
+            membrane: CLASS_1_MEMBRANE,
+            ..NeuronConfig::new(0.02, -0.1, -55.0, 6.0)
+        }
+    }
+
+    /// (H) Class 2 excitability.
+    pub fn class_2_excitable() -> NeuronConfig {
+        NeuronConfig::new(0.2, 0.26, -65.0, 0.0)
+    }
+
+    /// (I) Spike latency.
+    pub fn spike_latency() -> NeuronConfig {
+        NeuronConfig::new(0.02, 0.2, -65.0, 6.0)
+    }
+
+    /// (J) Subthreshold oscillations.
+    pub fn subthreshold_oscillations() -> NeuronConfig {
+        NeuronConfig::new(0.05, 0.26, -60.0, 0.0)
+    }
+
+    /// (K) Resonator.
+    pub fn resonator() -> NeuronConfig {
+        NeuronConfig::new(0.1, 0.26, -60.0, -1.0)
+    }
+
+    /// (L) Integrator.
+    pub fn integrator() -> NeuronConfig {
+        NeuronConfig {
+            membrane: CLASS_1_MEMBRANE,
+            ..NeuronConfig::new(0.02, -0.1, -55.0, 6.0)
+        }
+    }
+
+    /// (M) Rebound spike.
+    pub fn rebound_spike() -> NeuronConfig {
+        NeuronConfig::new(0.03, 0.25, -60.0, 4.0)
+    }
+
+    /// (N) Rebound burst.
+    pub fn rebound_burst() -> NeuronConfig {
+        NeuronConfig::new(0.03, 0.25, -52.0, 0.0)
+    }
+
+    /// (O) Threshold variability.
+    pub fn threshold_variability() -> NeuronConfig {
+        NeuronConfig::new(0.03, 0.25, -60.0, 4.0)
+    }
+
+    /// (P) Bistability.
+    pub fn bistability() -> NeuronConfig {
+        NeuronConfig::new(0.1, 0.26, -60.0, 0.0)
+    }
+
+    /// (Q) Depolarizing after-potential (DAP).
+    pub fn depolarizing_after_potential() -> NeuronConfig {
+        NeuronConfig::new(1.0, 0.2, -60.0, -21.0)
+    }
+
+    /// (R) Accommodation.
+    pub fn accommodation() -> NeuronConfig {
+        NeuronConfig {
+            accommodation: true,
+            ..NeuronConfig::new(0.02, 1.0, -55.0, 4.0)
+        }
+    }
+
+    /// (S) Inhibition-induced spiking.
+    pub fn inhibition_induced_spiking() -> NeuronConfig {
+        NeuronConfig::new(-0.02, -1.0, -60.0, 8.0)
+    }
+
+    /// (T) Inhibition-induced bursting.
+    pub fn inhibition_induced_bursting() -> NeuronConfig {
+        NeuronConfig::new(-0.026, -1.0, -45.0, -2.0)
+    }
+}
+
+/// The Izhikevich model. Unlike the default `NeuronModel::update`, a neuron
+/// whose potential crossed the threshold is reset one step later, so that the
+/// peak of the action potential is visible in its state.
+impl NeuronModel for NeuronConfig {
+    type State = NeuronState;
+
+    fn initial_state(&self) -> NeuronState {
+        NeuronState::new()
+    }
+
+    fn potential(&self, state: &NeuronState) -> Num {
+        state.potential()
+    }
+
+    fn step(&self, state: NeuronState, i_syn: Num, dt: Num, integrator: Integrator) -> NeuronState {
+        state.integrate_subthreshold(integrator, dt, i_syn, self)
+    }
+
+    fn spike_condition(&self, _previous: &NeuronState, state: &NeuronState) -> bool {
+        state.above_threshold()
+    }
+
+    fn reset(&self, state: NeuronState) -> NeuronState {
+        state.reset(self)
+    }
+
+    /// Moves the membrane equation by `delta` mV along `v`, i.e. replaces
+    /// `k2 * v^2 + k1 * v + k0` by `k2 * (v - delta)^2 + k1 * (v - delta) + k0`,
+    /// which raises the threshold (and the resting potential) by about `delta`.
+    fn has_threshold(&self) -> bool {
+        true
+    }
+
+    fn shift_threshold(&mut self, delta: Num) {
+        let [k2, k1, k0] = self.membrane;
+        self.membrane = [k2, k1 - 2.0 * k2 * delta, (k2 * delta - k1) * delta + k0];
+    }
+
+    fn has_adaptation(&self) -> bool {
+        true
+    }
+
+    /// Adapts the after-spike reset `d` of the recovery variable.
+    fn increase_adaptation(&mut self, delta: Num) {
+        self.d = (self.d + delta).max(0.0);
+    }
+
+    #[inline]
+    fn update(
+        &self,
+        state: NeuronState,
+        i_syn: Num,
+        dt: Num,
+        integrator: Integrator,
+    ) -> (NeuronState, NeuronActivity) {
+        state.integrate(integrator, dt, i_syn, self)
+    }
 }
@@ -0,0 +1,174 @@
+use crate::model::{Integrator, NeuronActivity};
+use crate::Num;
+use std::any::Any;
+use std::fmt::Debug;
+
+/// A spiking neuron model. The implementing type holds the model's
+/// parameters, while the dynamic variables are kept in `State`.
+pub trait NeuronModel: Debug {
+    /// The dynamic variables of a neuron.
+    type State: Copy + Debug;
+
+    /// The state a newly created neuron starts in.
+    fn initial_state(&self) -> Self::State;
+
+    /// The membrane potential (in mV).
+    fn potential(&self, state: &Self::State) -> Num;
+
+    /// Integrates `state` over `dt` ms with a constant input current `i_syn`.
+    fn step(&self, state: Self::State, i_syn: Num, dt: Num, integrator: Integrator) -> Self::State;
+
+    /// Whether the neuron emitted a spike when advancing from `previous` to `state`.
+    fn spike_condition(&self, previous: &Self::State, state: &Self::State) -> bool;
+
+    /// The state after the neuron emitted a spike. Models which generate
+    /// the action potential themselves (e.g. Hodgkin-Huxley) keep the default.
+    fn reset(&self, state: Self::State) -> Self::State {
+        state
+    }
+
+    /// Whether the model has a firing threshold, which `shift_threshold`
+    /// changes. Models without one (e.g. Hodgkin-Huxley) keep the default.
+    fn has_threshold(&self) -> bool {
+        false
+    }
+
+    /// Raises the firing threshold by `delta` mV (intrinsic plasticity).
+    /// Only called if `has_threshold`.
+    fn shift_threshold(&mut self, _delta: Num) {
+        panic!(
+            "{} has no firing threshold to shift",
+            std::any::type_name::<Self>()
+        );
+    }
+
+    /// Whether the model has a spike-triggered adaptation, which
+    /// `increase_adaptation` changes. Models without one (e.g. LIF) keep the
+    /// default.
+    fn has_adaptation(&self) -> bool {
+        false
+    }
+
+    /// Strengthens the spike-triggered adaptation by `delta` (intrinsic
+    /// plasticity). Only called if `has_adaptation`.
+    fn increase_adaptation(&mut self, _delta: Num) {
+        panic!(
+            "{} has no adaptation to increase",
+            std::any::type_name::<Self>()
+        );
+    }
+
+    /// The number of compartments, which synapses can target. Compartment 0
+    /// is the soma.
+    fn compartments(&self) -> usize {
+        1
+    }
+
+    /// The membrane potential of `compartment` (in mV).
+    fn compartment_potential(&self, state: &Self::State, _compartment: usize) -> Num {
+        self.potential(state)
+    }
+
+    /// Calculate the new state after `dt` ms.
+    fn update(
+        &self,
+        state: Self::State,
+        i_syn: Num,
+        dt: Num,
+        integrator: Integrator,
+    ) -> (Self::State, NeuronActivity) {
+        let new_state = self.step(state, i_syn, dt, integrator);
+        if self.spike_condition(&state, &new_state) {
+            (self.reset(new_state), NeuronActivity::Fires)
+        } else {
+            (new_state, NeuronActivity::Silent)
+        }
+    }
+
+    /// Like `update`, with `i_syn[k]` the input current into compartment
+    /// `k`. Multi-compartment models override it.
+    fn update_compartments(
+        &self,
+        state: Self::State,
+        i_syn: &[Num],
+        dt: Num,
+        integrator: Integrator,
+    ) -> (Self::State, NeuronActivity) {
+        self.update(state, i_syn[0], dt, integrator)
+    }
+}
+
+/// A `NeuronModel` together with its state. This erases the model type,
+/// so that neurons of different models can be part of the same network.
+pub(crate) trait ModelInstance: Debug {
+    fn update(&mut self, i_syn: &[Num], dt: Num, integrator: Integrator) -> NeuronActivity;
+
+    fn potential(&self) -> Num;
+
+    fn compartment_potential(&self, compartment: usize) -> Num;
+
+    fn has_threshold(&self) -> bool;
+
+    fn shift_threshold(&mut self, delta: Num);
+
+    fn has_adaptation(&self) -> bool;
+
+    fn increase_adaptation(&mut self, delta: Num);
+
+    fn as_any(&self) -> &dyn Any;
+}
+
+#[derive(Debug)]
+pub(crate) struct Instance<M: NeuronModel> {
+    pub(crate) model: M,
+    pub(crate) state: M::State,
+}
+
+impl<M: NeuronModel> Instance<M> {
+    pub(crate) fn new(model: M) -> Self {
+        let state = model.initial_state();
+        Instance { model, state }
+    }
+}
+
+impl<M> ModelInstance for Instance<M>
+where
+    M: NeuronModel + 'static,
+{
+    #[inline]
+    fn update(&mut self, i_syn: &[Num], dt: Num, integrator: Integrator) -> NeuronActivity {
+        let (new_state, activity) = self
+            .model
+            .update_compartments(self.state, i_syn, dt, integrator);
+        self.state = new_state;
+        activity
+    }
+
+    fn potential(&self) -> Num {
+        self.model.potential(&self.state)
+    }
+
+    fn compartment_potential(&self, compartment: usize) -> Num {
+        self.model.compartment_potential(&self.state, compartment)
+    }
+
+    fn has_threshold(&self) -> bool {
+        self.model.has_threshold()
+    }
+
+    fn shift_threshold(&mut self, delta: Num) {
+        self.model.shift_threshold(delta);
+    }
+
+    fn has_adaptation(&self) -> bool {
+        self.model.has_adaptation()
+    }
+
+    fn increase_adaptation(&mut self, delta: Num) {
+        self.model.increase_adaptation(delta);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
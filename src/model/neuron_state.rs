@@ -1,4 +1,4 @@
-use crate::model::{NeuronActivity, NeuronConfig};
+use crate::model::{Integrator, NeuronActivity, NeuronConfig, OdeSystem};
 use crate::Num;
 
 /// Threshold at which the neuron's potential is reset to `c`.
@@ -15,13 +15,56 @@ pub struct NeuronState {
 }
 
 #[inline(always)]
-fn dv(u: Num, v: Num, i_syn: Num) -> Num {
-    (0.04 * v + 5.0) * v + 140.0 - u + i_syn
+pub(crate) fn dv(u: Num, v: Num, i_syn: Num, config: &NeuronConfig) -> Num {
+    let [k2, k1, k0] = config.membrane;
+    (k2 * v + k1) * v + k0 - u + i_syn
 }
 
 #[inline(always)]
-fn du(u: Num, v: Num, a: Num, b: Num) -> Num {
-    a * (b * v - u)
+pub(crate) fn du(u: Num, v: Num, config: &NeuronConfig) -> Num {
+    if config.accommodation {
+        config.a * config.b * (v + 65.0)
+    } else {
+        config.a * (config.b * v - u)
+    }
+}
+
+/// The differential equations of a neuron receiving a constant input current.
+struct Dynamics<'a> {
+    config: &'a NeuronConfig,
+    i_syn: Num,
+}
+
+impl<'a> OdeSystem<2> for Dynamics<'a> {
+    #[inline(always)]
+    fn derivative(&self, &[v, u]: &[Num; 2]) -> [Num; 2] {
+        [dv(u, v, self.i_syn, self.config), du(u, v, self.config)]
+    }
+
+    #[inline(always)]
+    fn diagonal_jacobian(&self, &[v, _]: &[Num; 2]) -> [Num; 2] {
+        let [k2, k1, _] = self.config.membrane;
+        let du_du = if self.config.accommodation {
+            0.0
+        } else {
+            -self.config.a
+        };
+        [2.0 * k2 * v + k1, du_du]
+    }
+
+    /// Like the reference implementation, the step is completed after `v`
+    /// crossed the threshold, so that `u` keeps integrating until the neuron
+    /// is reset. Only a diverged potential stops the integration.
+    #[inline(always)]
+    fn escaped(&self, &[v, _]: &[Num; 2]) -> bool {
+        !v.is_finite()
+    }
+}
+
+impl Default for NeuronState {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl NeuronState {
@@ -29,6 +72,11 @@ impl NeuronState {
         NeuronState { v: -70.0, u: -14.0 }
     }
 
+    /// Creates a state with membrane potential `v` and recovery variable `u`.
+    pub fn with(v: Num, u: Num) -> NeuronState {
+        NeuronState { v, u }
+    }
+
     pub fn potential(&self) -> Num {
         if self.v < RESET_THRESHOLD {
             self.v
@@ -41,28 +89,80 @@ impl NeuronState {
         self.u
     }
 
-    #[inline(always)]
-    fn calc(self, dt: Num, i_syn: Num, config: &NeuronConfig) -> NeuronState {
-        NeuronState {
-            v: self.v + dt * dv(self.u, self.v, i_syn),
-            u: self.u + dt * du(self.u, self.v, config.a, config.b),
-        }
+    /// Calculate the new state after 1 ms, with the default integrator of
+    /// two forward Euler half-steps.
+    #[inline]
+    pub fn step_1ms(self, i_syn: Num, config: &NeuronConfig) -> (NeuronState, NeuronActivity) {
+        self.integrate(Integrator::default(), 1.0, i_syn, config)
     }
 
-    /// Calculate the new state after 1 ms.
+    /// Calculate the new state after `dt` ms using `integrator`.
+    ///
+    /// A neuron whose potential has reached the threshold fires and is reset
+    /// instead of being integrated.
     #[inline]
-    pub fn step_1ms(self, i_syn: Num, config: &NeuronConfig) -> (NeuronState, NeuronActivity) {
+    pub fn integrate(
+        self,
+        integrator: Integrator,
+        dt: Num,
+        i_syn: Num,
+        config: &NeuronConfig,
+    ) -> (NeuronState, NeuronActivity) {
         if self.v < RESET_THRESHOLD {
             (
-                // Split into two half-steps (0.5ms) to improve numerical stability
-                self.calc(0.5, i_syn, config).calc(0.5, i_syn, config),
+                self.integrate_subthreshold(integrator, dt, i_syn, config),
                 NeuronActivity::Silent,
             )
+        } else {
+            (self.reset(config), NeuronActivity::Fires)
+        }
+    }
+
+    /// Whether the potential has reached the threshold.
+    #[inline(always)]
+    pub(crate) fn above_threshold(&self) -> bool {
+        self.v >= RESET_THRESHOLD
+    }
+
+    /// The state after the neuron fired.
+    #[inline(always)]
+    pub(crate) fn reset(self, config: &NeuronConfig) -> NeuronState {
+        NeuronState {
+            v: config.c,
+            u: self.u + config.d,
+        }
+    }
+
+    /// Integrates the state over `dt` ms, without checking for spikes.
+    #[inline(always)]
+    pub(crate) fn integrate_subthreshold(
+        self,
+        integrator: Integrator,
+        dt: Num,
+        i_syn: Num,
+        config: &NeuronConfig,
+    ) -> NeuronState {
+        let dynamics = Dynamics { config, i_syn };
+        let [v, u] = integrator.integrate(&dynamics, [self.v, self.u], dt);
+        NeuronState { v, u }
+    }
+
+    /// Calculate the new state after a single step of `dt` ms, using the
+    /// integration scheme of the reference implementation of [2004]:
+    /// `v` is updated first, and `u` is then updated using the new `v`.
+    /// Unlike `step_1ms`, the neuron is reset within the same step in
+    /// which `v` crosses the threshold.
+    pub fn step(self, dt: Num, i_syn: Num, config: &NeuronConfig) -> (NeuronState, NeuronActivity) {
+        let v = self.v + dt * dv(self.u, self.v, i_syn, config);
+        let u = self.u + dt * du(self.u, v, config);
+
+        if v < RESET_THRESHOLD {
+            (NeuronState { v, u }, NeuronActivity::Silent)
         } else {
             (
                 NeuronState {
                     v: config.c,
-                    u: self.u + config.d,
+                    u: u + config.d,
                 },
                 NeuronActivity::Fires,
             )
@@ -1,22 +1,218 @@
-use crate::model::NeuronConfig;
-use crate::Closed01;
+use crate::model::{NeuronConfig, NeuronState, StimulusProtocol};
+use crate::{Closed01, Num};
 
 /// Datastructure representing one of the broad neuron types.
 /// Can be directly converted into a NeuronConfig.
+#[derive(Debug, Copy, Clone)]
 pub enum NeuronType {
     Excitatory(Closed01),
     Inhibitory(Closed01),
     RegularSpiking,
+    IntrinsicallyBursting,
     Chattering,
+    FastSpiking,
+    LowThresholdSpiking,
+    ThalamoCortical,
+    TonicSpiking,
+    PhasicSpiking,
+    TonicBursting,
+    PhasicBursting,
+    MixedMode,
+    SpikeFrequencyAdaptation,
+    Class1Excitable,
+    Class2Excitable,
+    SpikeLatency,
+    SubthresholdOscillations,
+    Resonator,
+    Integrator,
+    ReboundSpike,
+    ReboundBurst,
+    ThresholdVariability,
+    Bistability,
+    DepolarizingAfterPotential,
+    Accommodation,
+    InhibitionInducedSpiking,
+    InhibitionInducedBursting,
 }
 
-impl Into<NeuronConfig> for NeuronType {
-    fn into(self) -> NeuronConfig {
-        match self {
+impl From<NeuronType> for NeuronConfig {
+    fn from(neuron_type: NeuronType) -> NeuronConfig {
+        match neuron_type {
             NeuronType::Excitatory(r) => NeuronConfig::excitatory(r),
             NeuronType::Inhibitory(r) => NeuronConfig::inhibitory(r),
             NeuronType::RegularSpiking => NeuronConfig::regular_spiking(),
+            NeuronType::IntrinsicallyBursting => NeuronConfig::intrinsically_bursting(),
             NeuronType::Chattering => NeuronConfig::chattering(),
+            NeuronType::FastSpiking => NeuronConfig::fast_spiking(),
+            NeuronType::LowThresholdSpiking => NeuronConfig::low_threshold_spiking(),
+            NeuronType::ThalamoCortical => NeuronConfig::thalamo_cortical(),
+            NeuronType::TonicSpiking => NeuronConfig::tonic_spiking(),
+            NeuronType::PhasicSpiking => NeuronConfig::phasic_spiking(),
+            NeuronType::TonicBursting => NeuronConfig::tonic_bursting(),
+            NeuronType::PhasicBursting => NeuronConfig::phasic_bursting(),
+            NeuronType::MixedMode => NeuronConfig::mixed_mode(),
+            NeuronType::SpikeFrequencyAdaptation => NeuronConfig::spike_frequency_adaptation(),
+            NeuronType::Class1Excitable => NeuronConfig::class_1_excitable(),
+            NeuronType::Class2Excitable => NeuronConfig::class_2_excitable(),
+            NeuronType::SpikeLatency => NeuronConfig::spike_latency(),
+            NeuronType::SubthresholdOscillations => NeuronConfig::subthreshold_oscillations(),
+            NeuronType::Resonator => NeuronConfig::resonator(),
+            NeuronType::Integrator => NeuronConfig::integrator(),
+            NeuronType::ReboundSpike => NeuronConfig::rebound_spike(),
+            NeuronType::ReboundBurst => NeuronConfig::rebound_burst(),
+            NeuronType::ThresholdVariability => NeuronConfig::threshold_variability(),
+            NeuronType::Bistability => NeuronConfig::bistability(),
+            NeuronType::DepolarizingAfterPotential => NeuronConfig::depolarizing_after_potential(),
+            NeuronType::Accommodation => NeuronConfig::accommodation(),
+            NeuronType::InhibitionInducedSpiking => NeuronConfig::inhibition_induced_spiking(),
+            NeuronType::InhibitionInducedBursting => NeuronConfig::inhibition_induced_bursting(),
         }
     }
 }
+
+impl NeuronType {
+    /// The reference stimulus protocol demonstrating this neuron type's
+    /// firing behaviour. Protocols of the [2004] cell types are those of
+    /// the `figure1.m` script accompanying the paper; the cortical cell
+    /// types of [2003] are driven by a step current.
+    ///
+    /// Returns `None` for the randomized `Excitatory` and `Inhibitory` types.
+    pub fn protocol(&self) -> Option<StimulusProtocol> {
+        let protocol = match *self {
+            NeuronType::Excitatory(_) | NeuronType::Inhibitory(_) => return None,
+            NeuronType::RegularSpiking
+            | NeuronType::IntrinsicallyBursting
+            | NeuronType::Chattering
+            | NeuronType::FastSpiking
+            | NeuronType::LowThresholdSpiking => {
+                StimulusProtocol::new(-70.0, 0.2, 0.25, 200.0, |t| step(t, 20.0, 10.0))
+            }
+            NeuronType::ThalamoCortical => {
+                // hyperpolarized, then released, which results in a rebound burst.
+                StimulusProtocol::new(-87.0, 0.25, 0.25, 200.0, |t| {
+                    if t < 100.0 {
+                        -10.0
+                    } else {
+                        0.0
+                    }
+                })
+            }
+            NeuronType::TonicSpiking => {
+                StimulusProtocol::new(-70.0, 0.2, 0.25, 100.0, |t| step(t, 10.0, 14.0))
+            }
+            NeuronType::PhasicSpiking => {
+                StimulusProtocol::new(-64.0, 0.25, 0.25, 200.0, |t| step(t, 20.0, 0.5))
+            }
+            NeuronType::TonicBursting => {
+                StimulusProtocol::new(-70.0, 0.2, 0.25, 220.0, |t| step(t, 22.0, 15.0))
+            }
+            NeuronType::PhasicBursting => {
+                StimulusProtocol::new(-64.0, 0.25, 0.2, 200.0, |t| step(t, 20.0, 0.6))
+            }
+            NeuronType::MixedMode => {
+                StimulusProtocol::new(-70.0, 0.2, 0.25, 160.0, |t| step(t, 16.0, 10.0))
+            }
+            NeuronType::SpikeFrequencyAdaptation => {
+                StimulusProtocol::new(-70.0, 0.2, 0.25, 85.0, |t| step(t, 8.5, 30.0))
+            }
+            NeuronType::Class1Excitable => {
+                StimulusProtocol::new(-60.0, -0.1, 0.25, 300.0, |t| ramp(t, 30.0, 0.0, 0.075))
+            }
+            NeuronType::Class2Excitable => {
+                StimulusProtocol::new(-64.0, 0.26, 0.25, 300.0, |t| ramp(t, 30.0, -0.5, 0.015))
+            }
+            NeuronType::SpikeLatency => {
+                StimulusProtocol::new(-70.0, 0.2, 0.2, 100.0, |t| pulse(t, 10.0, 3.0, 7.04))
+            }
+            NeuronType::SubthresholdOscillations => {
+                StimulusProtocol::new(-62.0, 0.26, 0.25, 200.0, |t| pulse(t, 20.0, 5.0, 2.0))
+            }
+            NeuronType::Resonator => StimulusProtocol::new(-62.0, 0.26, 0.25, 400.0, |t| {
+                pulse(t, 40.0, 4.0, 0.65)
+                    + pulse(t, 60.0, 4.0, 0.65)
+                    + pulse(t, 280.0, 4.0, 0.65)
+                    + pulse(t, 320.0, 4.0, 0.65)
+            }),
+            NeuronType::Integrator => StimulusProtocol::new(-60.0, -0.1, 0.25, 100.0, |t| {
+                let t1 = 100.0 / 11.0;
+                pulse(t, t1, 2.0, 9.0)
+                    + pulse(t, t1 + 5.0, 2.0, 9.0)
+                    + pulse(t, 70.0, 2.0, 9.0)
+                    + pulse(t, 80.0, 2.0, 9.0)
+            }),
+            NeuronType::ReboundSpike | NeuronType::ReboundBurst => {
+                StimulusProtocol::new(-64.0, 0.25, 0.2, 200.0, |t| pulse(t, 20.0, 5.0, -15.0))
+            }
+            NeuronType::ThresholdVariability => {
+                StimulusProtocol::new(-64.0, 0.25, 0.25, 100.0, |t| {
+                    pulse(t, 10.0, 5.0, 1.0) + pulse(t, 70.0, 5.0, -6.0) + pulse(t, 80.0, 5.0, 1.0)
+                })
+            }
+            NeuronType::Bistability => StimulusProtocol::new(-61.0, 0.26, 0.25, 300.0, |t| {
+                0.24 + pulse(t, 37.5, 5.0, 1.0) + pulse(t, 216.0, 5.0, 1.0)
+            }),
+            NeuronType::DepolarizingAfterPotential => {
+                StimulusProtocol::new(-70.0, 0.2, 0.1, 50.0, |t| {
+                    if (t - 10.0).abs() < 1.0 {
+                        20.0
+                    } else {
+                        0.0
+                    }
+                })
+            }
+            NeuronType::Accommodation => StimulusProtocol {
+                initial_state: NeuronState::with(-65.0, -16.0),
+                dt: 0.5,
+                duration: 400.0,
+                input: |t| {
+                    if t < 200.0 {
+                        t / 25.0
+                    } else if t < 300.0 {
+                        0.0
+                    } else if t < 312.5 {
+                        (t - 300.0) / 12.5 * 4.0
+                    } else {
+                        0.0
+                    }
+                },
+            },
+            NeuronType::InhibitionInducedSpiking | NeuronType::InhibitionInducedBursting => {
+                StimulusProtocol::new(-63.8, -1.0, 0.5, 350.0, |t| {
+                    if (50.0..=250.0).contains(&t) {
+                        75.0
+                    } else {
+                        80.0
+                    }
+                })
+            }
+        };
+        Some(protocol)
+    }
+}
+
+/// A current `amplitude` switched on after `from`.
+fn step(t: Num, from: Num, amplitude: Num) -> Num {
+    if t > from {
+        amplitude
+    } else {
+        0.0
+    }
+}
+
+/// A current of `amplitude` during `from .. from + duration`.
+fn pulse(t: Num, from: Num, duration: Num, amplitude: Num) -> Num {
+    if t > from && t < from + duration {
+        amplitude
+    } else {
+        0.0
+    }
+}
+
+/// A current of `offset`, which rises with `slope` after `from`.
+fn ramp(t: Num, from: Num, offset: Num, slope: Num) -> Num {
+    if t > from {
+        offset + slope * (t - from)
+    } else {
+        offset
+    }
+}
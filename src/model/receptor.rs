@@ -0,0 +1,64 @@
+use crate::Num;
+
+/// The receptor type of a synapse, which determines how a pre-synaptic
+/// spike acts on the post-synaptic neuron.
+///
+/// The conductance-based receptors follow Izhikevich and Edelman (2008):
+/// a spike increases the receptor's conductance `g` by the synapse weight,
+/// which then decays exponentially, and the neuron receives the current
+/// `g * (e_rev - v)`.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum Receptor {
+    /// The weight is injected as a current pulse during the next time step.
+    #[default]
+    Current,
+
+    /// Fast excitatory glutamate receptor.
+    Ampa,
+
+    /// Slow excitatory glutamate receptor with voltage dependent Mg block.
+    Nmda,
+
+    /// Fast inhibitory receptor.
+    GabaA,
+
+    /// Slow inhibitory receptor.
+    GabaB,
+}
+
+/// Number of conductance-based receptor types.
+pub(crate) const CONDUCTANCES: usize = 4;
+
+impl Receptor {
+    /// Index into a neuron's conductances, or `None` for current-based synapses.
+    #[inline(always)]
+    pub(crate) fn conductance_index(self) -> Option<usize> {
+        match self {
+            Receptor::Current => None,
+            Receptor::Ampa => Some(0),
+            Receptor::Nmda => Some(1),
+            Receptor::GabaA => Some(2),
+            Receptor::GabaB => Some(3),
+        }
+    }
+}
+
+/// Decay time constants (in ms) of the AMPA, NMDA, GABA_A and GABA_B conductances.
+pub(crate) const TAU: [Num; CONDUCTANCES] = [5.0, 150.0, 6.0, 150.0];
+
+/// Reversal potentials (in mV) of the AMPA, NMDA, GABA_A and GABA_B receptors.
+const E_REV: [Num; CONDUCTANCES] = [0.0, 0.0, -70.0, -90.0];
+
+/// The synaptic current a neuron at potential `v` receives through the
+/// conductances `g` (ordered as `TAU`).
+#[inline]
+pub(crate) fn conductance_current(g: &[Num; CONDUCTANCES], v: Num) -> Num {
+    // Voltage dependent magnesium block of the NMDA receptor.
+    let x = ((v + 80.0) / 60.0) * ((v + 80.0) / 60.0);
+    let mg_block = x / (1.0 + x);
+
+    g[0] * (E_REV[0] - v)
+        + g[1] * mg_block * (E_REV[1] - v)
+        + g[2] * (E_REV[2] - v)
+        + g[3] * (E_REV[3] - v)
+}
@@ -0,0 +1,91 @@
+use crate::simulation::Time;
+use crate::Num;
+
+/// Parameters of short-term synaptic plasticity after Tsodyks, Pawelzik and
+/// Markram (1998).
+///
+/// A synapse has a fraction `x` of available resources and a utilisation
+/// `u`. Each presynaptic spike first increases `u` by `u_se * (1 - u)` and
+/// then releases the fraction `u` of the available resources, so that the
+/// effective weight of the spike is `weight * u * x`. Between spikes, `x`
+/// recovers to 1 with time constant `tau_rec` and `u` decays to 0 with time
+/// constant `tau_facil`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ShortTermPlasticity {
+    /// Utilisation of synaptic efficacy (U).
+    pub u_se: Num,
+
+    /// Recovery time constant of the resources (in ms).
+    pub tau_rec: Num,
+
+    /// Facilitation time constant (in ms). 0 disables facilitation.
+    pub tau_facil: Num,
+}
+
+impl ShortTermPlasticity {
+    pub fn new(u_se: Num, tau_rec: Num, tau_facil: Num) -> Self {
+        assert!(u_se > 0.0 && u_se <= 1.0);
+        assert!(tau_rec > 0.0 && tau_facil >= 0.0);
+        ShortTermPlasticity {
+            u_se,
+            tau_rec,
+            tau_facil,
+        }
+    }
+
+    /// A depressing cortical synapse (Tsodyks et al., 1998).
+    pub fn depressing() -> Self {
+        Self::new(0.5, 800.0, 0.0)
+    }
+
+    /// A facilitating cortical synapse (Tsodyks et al., 1998).
+    pub fn facilitating() -> Self {
+        Self::new(0.03, 130.0, 530.0)
+    }
+}
+
+/// The short-term plasticity state of a synapse.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct StpState {
+    pub(crate) config: ShortTermPlasticity,
+
+    /// available resources just before the last spike
+    x: Num,
+
+    /// utilisation at the last spike
+    u: Num,
+
+    /// time of the last spike (in ms)
+    last_spike: Time,
+}
+
+impl StpState {
+    pub(crate) fn new(config: ShortTermPlasticity) -> Self {
+        StpState {
+            config,
+            x: 1.0,
+            u: 0.0,
+            last_spike: Time::NEG_INFINITY,
+        }
+    }
+
+    /// A spike arrives at `time`. Returns the fraction of the weight it transmits.
+    #[inline]
+    pub(crate) fn transmit(&mut self, time: Time) -> Num {
+        let c = self.config;
+        let h = (time - self.last_spike) as Num;
+
+        let x = 1.0 - (1.0 - self.x * (1.0 - self.u)) * (-h / c.tau_rec).exp();
+        let u = if c.tau_facil > 0.0 {
+            self.u * (-h / c.tau_facil).exp()
+        } else {
+            0.0
+        };
+        let u = u + c.u_se * (1.0 - u);
+
+        self.x = x;
+        self.u = u;
+        self.last_spike = time;
+        u * x
+    }
+}
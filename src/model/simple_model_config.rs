@@ -0,0 +1,229 @@
+use crate::model::{Integrator, NeuronModel, OdeSystem};
+use crate::Num;
+
+/// Parameters of the simple model of Izhikevich, "Dynamical Systems in
+/// Neuroscience" (2007), in physiological units:
+///
+/// `C * dv/dt = k * (v - v_r) * (v - v_t) - u + I`
+///
+/// `du/dt = a * (b * (v - v_r) - u)`
+///
+/// Once `v` reaches `v_peak`, the neuron fires, `v` is reset to `c` and `u`
+/// is increased by `d`. Currents are in pA, the time in ms.
+#[derive(Debug, Clone)]
+pub struct SimpleModelConfig {
+    /// Membrane capacitance (in pF).
+    pub c_m: Num,
+
+    /// Scaling of the quadratic membrane current (in nS/mV).
+    pub k: Num,
+
+    /// Resting potential (in mV).
+    pub v_r: Num,
+
+    /// Instantaneous threshold potential (in mV).
+    pub v_t: Num,
+
+    /// Spike cutoff (in mV).
+    pub v_peak: Num,
+
+    /// Recovery time constant (in 1/ms).
+    pub a: Num,
+
+    /// Sensitivity of the recovery variable `u` to `v` (in nS).
+    pub b: Num,
+
+    /// After-spike reset value of the membrane potential (in mV).
+    pub c: Num,
+
+    /// After-spike increase of the recovery variable (in pA).
+    pub d: Num,
+
+    /// If set, `u` relaxes to `b * (v - v_b)^3` above `v_b` and to 0 below,
+    /// instead of `b * (v - v_r)`. This nonlinear nullcline is used by the
+    /// fast spiking interneuron.
+    pub v_b: Option<Num>,
+}
+
+impl SimpleModelConfig {
+    /// Layer 5 regular spiking pyramidal cell.
+    pub fn regular_spiking() -> Self {
+        SimpleModelConfig {
+            c_m: 100.0,
+            k: 0.7,
+            v_r: -60.0,
+            v_t: -40.0,
+            v_peak: 35.0,
+            a: 0.03,
+            b: -2.0,
+            c: -50.0,
+            d: 100.0,
+            v_b: None,
+        }
+    }
+
+    /// Layer 5 intrinsically bursting pyramidal cell.
+    pub fn intrinsically_bursting() -> Self {
+        SimpleModelConfig {
+            c_m: 150.0,
+            k: 1.2,
+            v_r: -75.0,
+            v_t: -45.0,
+            v_peak: 50.0,
+            a: 0.01,
+            b: 5.0,
+            c: -56.0,
+            d: 130.0,
+            v_b: None,
+        }
+    }
+
+    /// Layer 2/3 chattering (fast rhythmic bursting) pyramidal cell.
+    pub fn chattering() -> Self {
+        SimpleModelConfig {
+            c_m: 50.0,
+            k: 1.5,
+            v_r: -60.0,
+            v_t: -40.0,
+            v_peak: 25.0,
+            a: 0.03,
+            b: 1.0,
+            c: -40.0,
+            d: 150.0,
+            v_b: None,
+        }
+    }
+
+    /// Low-threshold spiking interneuron. The book additionally makes the
+    /// spike cutoff and reset depend on `u`, which is omitted here.
+    pub fn low_threshold_spiking() -> Self {
+        SimpleModelConfig {
+            c_m: 100.0,
+            k: 1.0,
+            v_r: -56.0,
+            v_t: -42.0,
+            v_peak: 40.0,
+            a: 0.03,
+            b: 8.0,
+            c: -53.0,
+            d: 20.0,
+            v_b: None,
+        }
+    }
+
+    /// Fast spiking (basket) interneuron.
+    pub fn fast_spiking() -> Self {
+        SimpleModelConfig {
+            c_m: 20.0,
+            k: 1.0,
+            v_r: -55.0,
+            v_t: -40.0,
+            v_peak: 25.0,
+            a: 0.2,
+            b: 0.025,
+            c: -45.0,
+            d: 0.0,
+            v_b: Some(-55.0),
+        }
+    }
+
+    /// The value `u` relaxes to at potential `v`.
+    #[inline(always)]
+    fn u_nullcline(&self, v: Num) -> Num {
+        match self.v_b {
+            None => self.b * (v - self.v_r),
+            Some(v_b) if v > v_b => self.b * (v - v_b) * (v - v_b) * (v - v_b),
+            Some(_) => 0.0,
+        }
+    }
+}
+
+/// State of a neuron of the simple model.
+#[derive(Copy, Clone, Debug)]
+pub struct SimpleModelState {
+    /// membrane potential of neuron (in mV)
+    pub v: Num,
+
+    /// recovery current (in pA)
+    pub u: Num,
+}
+
+struct Dynamics<'a> {
+    config: &'a SimpleModelConfig,
+    i_syn: Num,
+}
+
+impl<'a> OdeSystem<2> for Dynamics<'a> {
+    fn derivative(&self, &[v, u]: &[Num; 2]) -> [Num; 2] {
+        let c = self.config;
+        let dv = (c.k * (v - c.v_r) * (v - c.v_t) - u + self.i_syn) / c.c_m;
+        let du = c.a * (c.u_nullcline(v) - u);
+        [dv, du]
+    }
+
+    fn diagonal_jacobian(&self, &[v, _]: &[Num; 2]) -> [Num; 2] {
+        let c = self.config;
+        [c.k * (2.0 * v - c.v_r - c.v_t) / c.c_m, -c.a]
+    }
+
+    fn escaped(&self, &[v, _]: &[Num; 2]) -> bool {
+        v >= self.config.v_peak
+    }
+}
+
+impl NeuronModel for SimpleModelConfig {
+    type State = SimpleModelState;
+
+    fn initial_state(&self) -> SimpleModelState {
+        SimpleModelState {
+            v: self.v_r,
+            u: self.u_nullcline(self.v_r),
+        }
+    }
+
+    fn potential(&self, state: &SimpleModelState) -> Num {
+        state.v.min(self.v_peak)
+    }
+
+    fn step(
+        &self,
+        state: SimpleModelState,
+        i_syn: Num,
+        dt: Num,
+        integrator: Integrator,
+    ) -> SimpleModelState {
+        let dynamics = Dynamics {
+            config: self,
+            i_syn,
+        };
+        let [v, u] = integrator.integrate(&dynamics, [state.v, state.u], dt);
+        SimpleModelState { v, u }
+    }
+
+    fn spike_condition(&self, _previous: &SimpleModelState, state: &SimpleModelState) -> bool {
+        state.v >= self.v_peak
+    }
+
+    fn reset(&self, state: SimpleModelState) -> SimpleModelState {
+        SimpleModelState {
+            v: self.c,
+            u: state.u + self.d,
+        }
+    }
+
+    fn has_threshold(&self) -> bool {
+        true
+    }
+
+    fn shift_threshold(&mut self, delta: Num) {
+        self.v_t += delta;
+    }
+
+    fn has_adaptation(&self) -> bool {
+        true
+    }
+
+    fn increase_adaptation(&mut self, delta: Num) {
+        self.d = (self.d + delta).max(0.0);
+    }
+}
@@ -3,8 +3,7 @@ use crate::Num;
 /// Configuration parameters for Spike-Time Dependent Plasticity (STDP)
 #[derive(Debug, Copy, Clone)]
 pub struct StdpConfig {
-    /// By how much the `stdp` value of each neuron decays during every
-    /// simulator time step.
+    /// By how much the `stdp` value of each neuron decays per millisecond.
     pub decay: Num,
 
     /// The `stdp` value is reset to `fire_reset` when the neuron fires.
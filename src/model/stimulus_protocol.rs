@@ -0,0 +1,64 @@
+use crate::model::{NeuronConfig, NeuronState};
+use crate::Num;
+
+/// A stimulus protocol used to demonstrate a firing behaviour, as in the
+/// `figure1.m` script accompanying [2004].
+#[derive(Debug, Copy, Clone)]
+pub struct StimulusProtocol {
+    /// The state the neuron starts in.
+    pub initial_state: NeuronState,
+
+    /// Integration time step (in ms).
+    pub dt: Num,
+
+    /// Total duration of the protocol (in ms).
+    pub duration: Num,
+
+    /// The input current injected at time `t` (in ms).
+    pub input: fn(Num) -> Num,
+}
+
+impl StimulusProtocol {
+    /// A protocol starting at potential `v` with `u = b * v`.
+    pub(crate) fn new(
+        v: Num,
+        b: Num,
+        dt: Num,
+        duration: Num,
+        input: fn(Num) -> Num,
+    ) -> StimulusProtocol {
+        StimulusProtocol {
+            initial_state: NeuronState::with(v, b * v),
+            dt,
+            duration,
+            input,
+        }
+    }
+
+    /// Runs the protocol on a neuron configured with `config` and calls
+    /// `f(t, state, fires)` after every step.
+    pub fn run<F>(&self, config: &NeuronConfig, mut f: F)
+    where
+        F: FnMut(Num, &NeuronState, bool),
+    {
+        let steps = (self.duration / self.dt).round() as usize;
+        let mut state = self.initial_state;
+        for i in 0..=steps {
+            let t = i as Num * self.dt;
+            let (new_state, activity) = state.step(self.dt, (self.input)(t), config);
+            state = new_state;
+            f(t, &state, activity.fires());
+        }
+    }
+
+    /// The times (in ms) at which the neuron fires.
+    pub fn spike_times(&self, config: &NeuronConfig) -> Vec<Num> {
+        let mut spikes = Vec::new();
+        self.run(config, |t, _, fires| {
+            if fires {
+                spikes.push(t);
+            }
+        });
+        spikes
+    }
+}
@@ -0,0 +1,24 @@
+use crate::Num;
+
+/// The probability of connecting two neurons as a function of their
+/// distance.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ConnectionKernel {
+    /// `p_max * exp(-d^2 / (2 * sigma^2))`
+    Gaussian { p_max: Num, sigma: Num },
+
+    /// `p_max * exp(-d / lambda)`
+    Exponential { p_max: Num, lambda: Num },
+}
+
+impl ConnectionKernel {
+    /// The connection probability at `distance`.
+    pub fn probability(&self, distance: Num) -> Num {
+        match *self {
+            ConnectionKernel::Gaussian { p_max, sigma } => {
+                p_max * (-distance * distance / (2.0 * sigma * sigma)).exp()
+            }
+            ConnectionKernel::Exponential { p_max, lambda } => p_max * (-distance / lambda).exp(),
+        }
+    }
+}
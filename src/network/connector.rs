@@ -0,0 +1,54 @@
+use crate::network::{
+    DelayDistribution, SynapseConfig, SynapseDelay, Topology, WeightDistribution,
+};
+
+/// Generates the synapses between two groups of neurons (see
+/// `NetworkBuilder::connect_topology`).
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Connector {
+    /// Which pairs of neurons are connected.
+    pub topology: Topology,
+
+    pub weights: WeightDistribution,
+
+    pub delays: DelayDistribution,
+
+    /// Receptor, kernel and short-term plasticity of the synapses.
+    pub synapse: SynapseConfig,
+
+    /// Seed of the random number generator, which makes the generated
+    /// synapses reproducible.
+    pub seed: u64,
+}
+
+impl Connector {
+    /// Synapses of the default `SynapseConfig`, with a delay of 1 ms and
+    /// seed 0.
+    pub fn new(topology: Topology, weights: impl Into<WeightDistribution>) -> Self {
+        Connector {
+            topology,
+            weights: weights.into(),
+            delays: DelayDistribution::Constant(SynapseDelay::new(1)),
+            synapse: SynapseConfig::default(),
+            seed: 0,
+        }
+    }
+
+    pub fn with_delays(self, delays: impl Into<DelayDistribution>) -> Self {
+        Connector {
+            delays: delays.into(),
+            ..self
+        }
+    }
+
+    pub fn with_synapse(self, synapse: impl Into<SynapseConfig>) -> Self {
+        Connector {
+            synapse: synapse.into(),
+            ..self
+        }
+    }
+
+    pub fn with_seed(self, seed: u64) -> Self {
+        Connector { seed, ..self }
+    }
+}
@@ -0,0 +1,52 @@
+use crate::network::SynapseDelay;
+use crate::Num;
+use rand::Rng;
+
+/// The distribution of the delays of generated synapses.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum DelayDistribution {
+    /// Every synapse has the same delay.
+    Constant(SynapseDelay),
+
+    /// Delays uniformly distributed in `min ..= max`, with microsecond
+    /// resolution.
+    Uniform {
+        min: SynapseDelay,
+        max: SynapseDelay,
+    },
+
+    /// The time a spike travels from the pre- to the post-synaptic neuron
+    /// at `velocity` m/s (mm/ms), plus the `synaptic_delay`. Both neurons
+    /// need a position.
+    Conduction {
+        velocity: Num,
+        synaptic_delay: SynapseDelay,
+    },
+}
+
+impl DelayDistribution {
+    /// A delay of a synapse between neurons at `distance` mm, if known.
+    pub fn sample<R: Rng>(&self, rng: &mut R, distance: Option<Num>) -> SynapseDelay {
+        match *self {
+            DelayDistribution::Constant(delay) => delay,
+            DelayDistribution::Uniform { min, max } => {
+                assert!(min <= max);
+                SynapseDelay::from_us(rng.gen_range(min.as_us(), max.as_us() + 1))
+            }
+            DelayDistribution::Conduction {
+                velocity,
+                synaptic_delay,
+            } => {
+                assert!(velocity > 0.0);
+                let distance = distance.expect("conduction delays require neuron positions");
+                SynapseDelay::from_ms(synaptic_delay.as_ms() + distance / velocity)
+            }
+        }
+    }
+}
+
+impl From<SynapseDelay> for DelayDistribution {
+    fn from(delay: SynapseDelay) -> Self {
+        DelayDistribution::Constant(delay)
+    }
+}
@@ -0,0 +1,24 @@
+use crate::network::NeuronId;
+use crate::Num;
+
+/// A symmetric electrical synapse. It passes the current
+/// `conductance * (v_other - v)` to each of its neurons, without delay.
+#[derive(Debug, Copy, Clone)]
+pub struct GapJunction {
+    pub(crate) neurons: (NeuronId, NeuronId),
+    pub(crate) conductance: Num,
+}
+
+impl GapJunction {
+    pub fn neurons(&self) -> (NeuronId, NeuronId) {
+        self.neurons
+    }
+
+    pub fn conductance(&self) -> Num {
+        self.conductance
+    }
+
+    pub fn set_conductance(&mut self, conductance: Num) {
+        self.conductance = conductance;
+    }
+}
@@ -0,0 +1,65 @@
+use crate::network::{Position, Space};
+use crate::Num;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// An arrangement of a group of neurons in space (see `NetworkBuilder::place`).
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Layout {
+    /// A grid of `shape` with `spacing` mm between neighbours, filled in
+    /// row-major order. Use a `shape` of `[nx, ny, 1]` for a 2D sheet.
+    Grid { shape: [usize; 3], spacing: Num },
+
+    /// Uniformly random positions in a box of `size` mm at the origin.
+    RandomBox { size: [Num; 3], seed: u64 },
+
+    /// A grid like `Grid`, in a space which wraps around at its borders.
+    Torus { shape: [usize; 3], spacing: Num },
+}
+
+impl Layout {
+    /// The positions of `n` neurons.
+    pub fn positions(&self, n: usize) -> Vec<Position> {
+        match *self {
+            Layout::Grid { shape, spacing } | Layout::Torus { shape, spacing } => {
+                let [nx, ny, nz] = shape;
+                assert_eq!(n, nx * ny * nz);
+                (0..n)
+                    .map(|i| {
+                        Position::new(
+                            (i % nx) as Num * spacing,
+                            ((i / nx) % ny) as Num * spacing,
+                            (i / (nx * ny)) as Num * spacing,
+                        )
+                    })
+                    .collect()
+            }
+            Layout::RandomBox { size, seed } => {
+                let mut rng = StdRng::seed_from_u64(seed);
+                (0..n)
+                    .map(|_| {
+                        Position::new(
+                            size[0] * rng.gen::<Num>(),
+                            size[1] * rng.gen::<Num>(),
+                            size[2] * rng.gen::<Num>(),
+                        )
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    /// The space implied by the layout.
+    pub fn space(&self) -> Space {
+        match *self {
+            Layout::Torus { shape, spacing } => Space::Torus {
+                extent: [
+                    shape[0] as Num * spacing,
+                    shape[1] as Num * spacing,
+                    shape[2] as Num * spacing,
+                ],
+            },
+            _ => Space::Euclidean,
+        }
+    }
+}
@@ -1,15 +1,49 @@
+pub mod connection_kernel;
+pub mod connector;
+pub mod delay_distribution;
+pub mod gap_junction;
+pub mod layout;
+#[allow(clippy::module_inception)]
 pub mod network;
 pub mod network_builder;
 pub mod neuron;
 pub mod neuron_id;
+pub mod neuron_type_distribution;
+pub mod population;
+pub mod population_id;
+pub mod position;
+pub mod projection;
+pub mod projection_id;
+pub mod refractory_period;
+pub mod space;
 pub mod synapse;
+pub mod synapse_config;
 pub mod synapse_delay;
 pub mod synapse_id;
+pub(crate) mod synaptic_input;
+pub mod topology;
+pub mod weight_distribution;
 
+pub use connection_kernel::ConnectionKernel;
+pub use connector::Connector;
+pub use delay_distribution::DelayDistribution;
+pub use gap_junction::GapJunction;
+pub use layout::Layout;
 pub use network::Network;
 pub use network_builder::NetworkBuilder;
 pub use neuron::Neuron;
 pub use neuron_id::NeuronId;
+pub use neuron_type_distribution::NeuronTypeDistribution;
+pub use population::Population;
+pub use population_id::PopulationId;
+pub use position::Position;
+pub use projection::Projection;
+pub use projection_id::ProjectionId;
+pub use refractory_period::RefractoryPeriod;
+pub use space::Space;
 pub use synapse::Synapse;
+pub use synapse_config::SynapseConfig;
 pub use synapse_delay::SynapseDelay;
 pub use synapse_id::SynapseId;
+pub use topology::Topology;
+pub use weight_distribution::WeightDistribution;
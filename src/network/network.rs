@@ -1,46 +1,385 @@
-use crate::model::NeuronState;
-use crate::network::{Neuron, NeuronId, Synapse, SynapseDelay, SynapseId};
+use crate::model::NeuronModel;
+use crate::network::{
+    GapJunction, Neuron, NeuronId, Population, PopulationId, Projection, ProjectionId, Space,
+    Synapse, SynapseConfig, SynapseDelay, SynapseId,
+};
+use crate::simulation::Time;
 use crate::Num;
 
 #[derive(Debug)]
 pub struct Network {
     pub(crate) neurons: Vec<Neuron>,
     pub(crate) synapses: Vec<Synapse>,
+    pub(crate) gap_junctions: Vec<GapJunction>,
+
+    // slots of removed neurons and synapses, which are reused first
+    pub(crate) free_neurons: Vec<usize>,
+    pub(crate) free_synapses: Vec<usize>,
+
+    // the geometry of the neurons' positions
+    pub(crate) space: Space,
+
+    pub(crate) populations: Vec<Population>,
+    pub(crate) projections: Vec<Projection>,
+
+    /// Dopamine concentration, which gates reward-modulated plasticity.
+    pub(crate) dopamine: Num,
+
+    /// Time constant with which the dopamine concentration decays (in ms).
+    pub(crate) dopamine_tau: Num,
 }
 
 impl Network {
     pub fn neuron_ref(&self, neuron_id: NeuronId) -> &Neuron {
+        assert!(self.contains_neuron(neuron_id));
         &self.neurons[neuron_id.index()]
     }
 
     pub fn neuron_mut(&mut self, neuron_id: NeuronId) -> &mut Neuron {
+        assert!(self.contains_neuron(neuron_id));
         &mut self.neurons[neuron_id.index()]
     }
 
-    pub fn save_state(&self) -> Vec<NeuronState> {
+    /// The internal state of the neuron in each slot. Removed neurons and
+    /// neurons of another model than `M` are reported as `None`.
+    pub fn save_state<M: NeuronModel + 'static>(&self) -> Vec<Option<M::State>> {
         self.neurons
             .iter()
-            .enumerate()
-            .map(|(_, n)| n.state)
+            .map(|n| {
+                if n.removed {
+                    None
+                } else {
+                    n.state::<M>().copied()
+                }
+            })
             .collect()
     }
 
+    /// The number of neurons, not counting removed ones.
     pub fn total_neurons(&self) -> usize {
-        self.neurons.len()
+        self.neurons.len() - self.free_neurons.len()
+    }
+
+    /// Whether `neuron_id` refers to a neuron which has not been removed.
+    pub fn contains_neuron(&self, neuron_id: NeuronId) -> bool {
+        self.neurons
+            .get(neuron_id.index())
+            .is_some_and(|n| !n.removed && n.generation == neuron_id.generation())
+    }
+
+    /// The neuron `neuron_id`, unless it has been removed.
+    pub(crate) fn live_neuron_mut(&mut self, neuron_id: NeuronId) -> Option<&mut Neuron> {
+        if self.contains_neuron(neuron_id) {
+            Some(&mut self.neurons[neuron_id.index()])
+        } else {
+            None
+        }
+    }
+
+    /// The ids of all neurons, in the order of their slots.
+    pub fn neuron_ids(&self) -> impl Iterator<Item = NeuronId> + '_ {
+        self.neurons
+            .iter()
+            .enumerate()
+            .filter(|(_, n)| !n.removed)
+            .map(|(i, n)| NeuronId::new(i, n.generation))
+    }
+
+    pub fn space(&self) -> Space {
+        self.space
+    }
+
+    /// The distance between two neurons (in mm), if both have a position.
+    pub fn distance(&self, a: NeuronId, b: NeuronId) -> Option<Num> {
+        match (self.neuron_ref(a).position, self.neuron_ref(b).position) {
+            (Some(a), Some(b)) => Some(self.space.distance(a, b)),
+            _ => None,
+        }
+    }
+
+    pub fn populations(&self) -> &[Population] {
+        &self.populations
+    }
+
+    pub fn population(&self, population_id: PopulationId) -> &Population {
+        &self.populations[population_id.index()]
+    }
+
+    pub fn population_mut(&mut self, population_id: PopulationId) -> &mut Population {
+        &mut self.populations[population_id.index()]
+    }
+
+    /// The population named `name`, if any.
+    pub fn find_population(&self, name: &str) -> Option<PopulationId> {
+        self.populations
+            .iter()
+            .position(|p| p.name == name)
+            .map(PopulationId::from)
+    }
+
+    pub fn projections(&self) -> &[Projection] {
+        &self.projections
+    }
+
+    pub fn projection(&self, projection_id: ProjectionId) -> &Projection {
+        &self.projections[projection_id.index()]
+    }
+
+    /// The projection named `name`, if any.
+    pub fn find_projection(&self, name: &str) -> Option<ProjectionId> {
+        self.projections
+            .iter()
+            .position(|p| p.name == name)
+            .map(ProjectionId::from)
+    }
+
+    pub fn synapse_ref(&self, synapse_id: SynapseId) -> &Synapse {
+        assert!(self.contains_synapse(synapse_id));
+        &self.synapses[synapse_id.index()]
+    }
+
+    pub fn synapse_mut(&mut self, synapse_id: SynapseId) -> &mut Synapse {
+        assert!(self.contains_synapse(synapse_id));
+        &mut self.synapses[synapse_id.index()]
+    }
+
+    /// The number of synapses, not counting removed ones.
+    pub fn total_synapses(&self) -> usize {
+        self.synapses.len() - self.free_synapses.len()
+    }
+
+    /// Whether `synapse_id` refers to a synapse which has not been removed.
+    pub fn contains_synapse(&self, synapse_id: SynapseId) -> bool {
+        self.synapses
+            .get(synapse_id.index())
+            .is_some_and(|s| !s.removed && s.generation == synapse_id.generation())
+    }
+
+    /// Whether `synapse_id` is changed by plasticity rule `rule`.
+    pub(crate) fn is_plastic(&self, synapse_id: SynapseId, rule: usize) -> bool {
+        self.synapses[synapse_id.index()].plasticity_rule == Some(rule)
+    }
+
+    /// The ids of all synapses, in the order of their slots.
+    pub fn synapse_ids(&self) -> impl Iterator<Item = SynapseId> + '_ {
+        self.synapses
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| !s.removed)
+            .map(|(i, s)| SynapseId::new(i, s.generation))
+    }
+
+    /// Adds a neuron to the network. Its external input is zero.
+    ///
+    /// The slot of a removed neuron is reused with the next generation, so
+    /// the new neuron does not inherit the state plasticity rules kept for
+    /// the removed one.
+    pub fn add_neuron<M: NeuronModel + 'static>(&mut self, model: M) -> NeuronId {
+        let mut neuron = Neuron::new(model);
+        match self.free_neurons.pop() {
+            Some(index) => {
+                neuron.generation = self.neurons[index].generation + 1;
+                self.neurons[index] = neuron;
+                NeuronId::new(index, self.neurons[index].generation)
+            }
+            None => {
+                self.neurons.push(neuron);
+                NeuronId::from(self.neurons.len() - 1)
+            }
+        }
     }
 
-    /// Reset the input currents of all neurons
+    /// Removes `neuron_id` together with its synapses and gap junctions.
+    pub fn remove_neuron(&mut self, neuron_id: NeuronId) {
+        assert!(self.contains_neuron(neuron_id));
+        let neuron = &self.neurons[neuron_id.index()];
+        let synapses: Vec<SynapseId> = neuron
+            .pre_synapses
+            .iter()
+            .chain(neuron.post_synapses.iter())
+            .copied()
+            .collect();
+        for synapse_id in synapses {
+            // a synapse onto the neuron itself is listed twice
+            if self.contains_synapse(synapse_id) {
+                self.disconnect(synapse_id);
+            }
+        }
+        self.gap_junctions
+            .retain(|g| g.neurons.0 != neuron_id && g.neurons.1 != neuron_id);
+
+        let neuron = &mut self.neurons[neuron_id.index()];
+        neuron.removed = true;
+        neuron.i_ext = 0.0;
+        self.free_neurons.push(neuron_id.index());
+    }
+
+    /// Connects `pre_neuron` with `post_neuron`, like `NetworkBuilder::connect`.
+    /// The slot of a removed synapse is reused.
+    pub fn connect(
+        &mut self,
+        pre_neuron: NeuronId,
+        post_neuron: NeuronId,
+        synapse_delay: SynapseDelay,
+        weight: Num,
+        synapse: impl Into<SynapseConfig>,
+    ) -> SynapseId {
+        assert!(self.contains_neuron(pre_neuron));
+        assert!(self.contains_neuron(post_neuron));
+
+        let mut synapse = Synapse::new(
+            pre_neuron,
+            post_neuron,
+            synapse_delay,
+            weight,
+            synapse.into(),
+        );
+        assert!(synapse.compartment < self.neurons[post_neuron.index()].compartments());
+        let synapse_id = match self.free_synapses.pop() {
+            Some(index) => {
+                synapse.generation = self.synapses[index].generation + 1;
+                self.synapses[index] = synapse;
+                SynapseId::new(index, self.synapses[index].generation)
+            }
+            None => {
+                self.synapses.push(synapse);
+                SynapseId::from(self.synapses.len() - 1)
+            }
+        };
+
+        self.neurons[pre_neuron.index()]
+            .post_synapses
+            .push(synapse_id);
+        self.neurons[post_neuron.index()]
+            .pre_synapses
+            .push(synapse_id);
+
+        synapse_id
+    }
+
+    /// Removes `synapse_id`. Spikes already travelling along it are dropped.
+    pub fn disconnect(&mut self, synapse_id: SynapseId) {
+        assert!(self.contains_synapse(synapse_id));
+        self.unlink(synapse_id);
+        self.synapses[synapse_id.index()].removed = true;
+        self.free_synapses.push(synapse_id.index());
+    }
+
+    /// Moves `synapse_id` to connect `pre_neuron` with `post_neuron`,
+    /// keeping its weight, delay and receptor. Spikes already travelling
+    /// along it arrive at the new post-synaptic neuron.
+    pub fn rewire(&mut self, synapse_id: SynapseId, pre_neuron: NeuronId, post_neuron: NeuronId) {
+        assert!(self.contains_synapse(synapse_id));
+        assert!(self.contains_neuron(pre_neuron));
+        assert!(self.contains_neuron(post_neuron));
+        assert!(
+            self.synapses[synapse_id.index()].compartment
+                < self.neurons[post_neuron.index()].compartments()
+        );
+
+        self.unlink(synapse_id);
+        let synapse = &mut self.synapses[synapse_id.index()];
+        synapse.pre_neuron = pre_neuron;
+        synapse.post_neuron = post_neuron;
+        self.neurons[pre_neuron.index()]
+            .post_synapses
+            .push(synapse_id);
+        self.neurons[post_neuron.index()]
+            .pre_synapses
+            .push(synapse_id);
+    }
+
+    /// Removes `synapse_id` from the synapse lists of its neurons.
+    fn unlink(&mut self, synapse_id: SynapseId) {
+        let synapse = &self.synapses[synapse_id.index()];
+        let (pre_neuron, post_neuron) = (synapse.pre_neuron, synapse.post_neuron);
+        self.neurons[pre_neuron.index()]
+            .post_synapses
+            .retain(|&s| s != synapse_id);
+        self.neurons[post_neuron.index()]
+            .pre_synapses
+            .retain(|&s| s != synapse_id);
+    }
+
+    pub fn gap_junctions(&self) -> &[GapJunction] {
+        &self.gap_junctions
+    }
+
+    pub fn gap_junctions_mut(&mut self) -> &mut [GapJunction] {
+        &mut self.gap_junctions
+    }
+
+    /// Computes the currents through the gap junctions from the present
+    /// membrane potentials.
+    pub fn apply_gap_junctions(&mut self) {
+        for gap_junction in self.gap_junctions.iter() {
+            let (a, b) = gap_junction.neurons;
+            let current = gap_junction.conductance
+                * (self.neurons[b.index()].potential() - self.neurons[a.index()].potential());
+            self.neurons[a.index()].i_gap += current;
+            self.neurons[b.index()].i_gap -= current;
+        }
+    }
+
+    /// Reset the synaptic and noise input currents of all neurons
     pub fn reset_all_input_currents(&mut self) {
         for neuron in self.neurons.iter_mut() {
-            neuron.i_inp = 0.0;
+            for input in neuron.input.iter_mut() {
+                input.i_inp = 0.0;
+            }
+            neuron.i_noise = 0.0;
+            neuron.i_gap = 0.0;
         }
     }
 
+    /// Releases `amount` of dopamine, e.g. 0.5 for a reward as in Izhikevich
+    /// (2007). The concentration decays with `dopamine_time_constant`.
+    pub fn deliver_reward(&mut self, amount: Num) {
+        self.dopamine += amount;
+    }
+
+    /// The current dopamine concentration.
+    pub fn dopamine(&self) -> Num {
+        self.dopamine
+    }
+
+    /// Time constant of the dopamine concentration (in ms), 200 ms by
+    /// default as in Izhikevich (2007).
+    pub fn dopamine_time_constant(&self) -> Num {
+        self.dopamine_tau
+    }
+
+    pub fn set_dopamine_time_constant(&mut self, tau: Num) {
+        assert!(tau > 0.0);
+        self.dopamine_tau = tau;
+    }
+
+    /// Lets the dopamine concentration decay for `dt` ms.
+    pub(crate) fn decay_dopamine(&mut self, dt: Num) {
+        self.dopamine *= (-dt / self.dopamine_tau).exp();
+    }
+
     /// Excite `neuron_id` with `current`.
     pub fn set_external_input(&mut self, neuron_id: NeuronId, current: Num) {
         self.neuron_mut(neuron_id).i_ext = current;
     }
 
+    /// Excite every neuron of `population_id` which has not been removed
+    /// with `current`.
+    pub fn set_population_input(&mut self, population_id: PopulationId, current: Num) {
+        let Network {
+            populations,
+            neurons,
+            ..
+        } = self;
+        for &neuron_id in populations[population_id.index()].neurons.iter() {
+            let neuron = &mut neurons[neuron_id.index()];
+            if !neuron.removed && neuron.generation == neuron_id.generation() {
+                neuron.i_ext = current;
+            }
+        }
+    }
+
     pub fn get_external_input(&self, neuron_id: NeuronId) -> Num {
         self.neuron_ref(neuron_id).i_ext
     }
@@ -49,43 +388,41 @@ impl Network {
         self.neuron_mut(neuron_id).i_ext += additional_current;
     }
 
-    pub fn process_firing_synapse(&mut self, firing_synapse: SynapseId) {
+    /// A spike arrives at `firing_synapse` at `time` (in ms).
+    pub fn process_firing_synapse(&mut self, firing_synapse: SynapseId, time: Time) {
         let syn = &mut self.synapses[firing_synapse.index()];
 
-        let pre_neuron_stdp = self.neurons[syn.pre_neuron.index()].stdp;
         let post_neuron = &mut self.neurons[syn.post_neuron.index()];
-        let post_neuron_stdp = post_neuron.stdp;
-
-        post_neuron.i_inp += syn.weight;
+        let weight = match syn.stp {
+            Some(ref mut stp) => syn.weight * stp.transmit(time),
+            None => syn.weight,
+        };
 
-        // whenever a spike arrives here at it's post_neuron, this means, that
-        // the pre-neuron fired some time ago (delay time-steps). It can be the
-        // case that the post_neuron has fired ealier, in which case we have to
-        // depress the synapse according to the STDP rule.
-        syn.eff_d += pre_neuron_stdp - post_neuron_stdp;
-    }
+        // transmitter is released, but a refractory neuron ignores it.
+        if post_neuron.is_refractory() {
+            return;
+        }
 
-    /// The synapses `firing_synapses` fire. Update the network state.
-    pub fn process_firing_synapses(&mut self, firing_synapses: &[SynapseId]) {
-        for &syn_id in firing_synapses {
-            self.process_firing_synapse(syn_id);
+        let input = &mut post_neuron.input[syn.compartment];
+        match syn.receptor.conductance_index() {
+            None => input.excite(syn.kernel, weight),
+            Some(receptor) => input.g_syn[receptor] += weight,
         }
     }
 
-    // Excite the synapses that might have led to the firing of the underlying neuron.
-    // We do this by adding the synapses pre_neuron's STDP value to the synapses eff_d
-    // (efficacy derivative) value.
-    //
-    // We do not update the synapses weight value immediatly, but only once very while
-    // (TODO), so that STDP reflects more LTP (Long Term Potentiation).
-    pub fn excite_all_pre_synapses_of_neuron(&mut self, neuron_id: NeuronId) {
-        for &synapse_id in self.neurons[neuron_id.index()].pre_synapses.iter() {
-            let synapse = &mut self.synapses[synapse_id.index()];
-            let stdp = self.neurons[synapse.pre_neuron.index()].stdp;
-            synapse.eff_d += stdp;
+    /// The synapses `firing_synapses` fire at `time`. Update the network state.
+    pub fn process_firing_synapses(&mut self, firing_synapses: &[SynapseId], time: Time) {
+        for &syn_id in firing_synapses {
+            self.process_firing_synapse(syn_id, time);
         }
     }
 
+    /// Adds the accumulated `eff_d` to the weight of every plastic synapse,
+    /// restricted to `min_syn_weight ..= max_syn_weight`, and decays `eff_d`
+    /// by `eff_d_decay`.
+    ///
+    /// We do not update the synapses weight value immediatly, but only once very while,
+    /// so that STDP reflects more LTP (Long Term Potentiation).
     pub fn update_synapse_weights(
         &mut self,
         // range
@@ -93,7 +430,45 @@ impl Network {
         max_syn_weight: Num,
         eff_d_decay: Num,
     ) {
-        for syn in self.synapses.iter_mut() {
+        self.update_weights_where(
+            |rule| rule.is_some(),
+            min_syn_weight,
+            max_syn_weight,
+            eff_d_decay,
+        );
+    }
+
+    /// Like `update_synapse_weights`, for the synapses of plasticity rule
+    /// `rule` only (see `SynapseConfig::plasticity_rule`).
+    pub fn update_rule_weights(
+        &mut self,
+        rule: usize,
+        min_syn_weight: Num,
+        max_syn_weight: Num,
+        eff_d_decay: Num,
+    ) {
+        self.update_weights_where(
+            |r| r == Some(rule),
+            min_syn_weight,
+            max_syn_weight,
+            eff_d_decay,
+        );
+    }
+
+    fn update_weights_where<F>(
+        &mut self,
+        filter: F,
+        min_syn_weight: Num,
+        max_syn_weight: Num,
+        eff_d_decay: Num,
+    ) where
+        F: Fn(Option<usize>) -> bool,
+    {
+        for syn in self
+            .synapses
+            .iter_mut()
+            .filter(|syn| !syn.removed && filter(syn.plasticity_rule))
+        {
             let new_weight = syn.weight + syn.eff_d;
 
             // Restrict synapse weight min_syn_weight .. max_syn_weight
@@ -102,12 +477,13 @@ impl Network {
         }
     }
 
-    pub fn max_synapse_delay(&self) -> SynapseDelay {
+    /// The longest delay of all synapses, or `None` without synapses.
+    pub fn max_synapse_delay(&self) -> Option<SynapseDelay> {
         self.synapses
             .iter()
+            .filter(|syn| !syn.removed)
             .map(|syn| syn.synapse_delay)
             .max()
-            .unwrap()
     }
 }
 
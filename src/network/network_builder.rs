@@ -1,11 +1,27 @@
-use crate::model::{NeuronConfig, NeuronState};
-use crate::network::{Network, Neuron, NeuronId, Synapse, SynapseDelay, SynapseId};
+use crate::model::{NeuronConfig, NeuronModel};
+use crate::network::{
+    Connector, GapJunction, Layout, Network, Neuron, NeuronId, NeuronTypeDistribution, Population,
+    PopulationId, Projection, ProjectionId, RefractoryPeriod, Space, Synapse, SynapseConfig,
+    SynapseDelay, SynapseId,
+};
 use crate::Num;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 
 #[derive(Debug)]
 pub struct NetworkBuilder {
     neurons: Vec<Neuron>,
     synapses: Vec<Synapse>,
+    gap_junctions: Vec<GapJunction>,
+    space: Space,
+    populations: Vec<Population>,
+    projections: Vec<Projection>,
+}
+
+impl Default for NetworkBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl NetworkBuilder {
@@ -13,79 +29,226 @@ impl NetworkBuilder {
         NetworkBuilder {
             neurons: Vec::new(),
             synapses: Vec::new(),
+            gap_junctions: Vec::new(),
+            space: Space::default(),
+            populations: Vec::new(),
+            projections: Vec::new(),
         }
     }
 
-    pub fn create_neuron(&mut self, config: NeuronConfig) -> NeuronId {
-        let neuron = Neuron {
-            state: NeuronState::new(),
-            config: config,
-            stdp: 0.0,
-            i_ext: 0.0, // external current
-            i_inp: 0.0, // synaptic current
-            // connectivity
-            pre_synapses: Vec::new(),
-            post_synapses: Vec::new(),
-        };
+    pub fn create_neuron<M: NeuronModel + 'static>(&mut self, model: M) -> NeuronId {
         let neuron_id = NeuronId::from(self.neurons.len());
-        self.neurons.push(neuron);
-        return neuron_id;
+        self.neurons.push(Neuron::new(model));
+        neuron_id
     }
 
-    pub fn create_n_neurons_with<F>(&mut self, n: usize, f: &mut F) -> Vec<NeuronId>
+    pub fn create_n_neurons_with<F, M>(&mut self, n: usize, f: &mut F) -> Vec<NeuronId>
     where
-        F: FnMut(usize) -> NeuronConfig,
+        F: FnMut(usize) -> M,
+        M: NeuronModel + 'static,
     {
         (0..n).map(|i| self.create_neuron(f(i))).collect()
     }
 
+    /// Creates a population of `size` neurons named `name`, whose types are
+    /// drawn from `neuron_types` with a random number generator seeded with
+    /// `seed`.
+    pub fn create_population(
+        &mut self,
+        name: &str,
+        size: usize,
+        neuron_types: impl Into<NeuronTypeDistribution>,
+        seed: u64,
+    ) -> PopulationId {
+        let neuron_types = neuron_types.into();
+        let mut rng = StdRng::seed_from_u64(seed);
+        let neurons = self.create_n_neurons_with(size, &mut |_| {
+            NeuronConfig::from(neuron_types.sample(&mut rng))
+        });
+        self.add_population(Population::new(name, neurons, Some(neuron_types)))
+    }
+
+    /// Creates a population of `size` neurons named `name`, of the models
+    /// returned by `f` for the index of each neuron.
+    pub fn create_population_with<F, M>(
+        &mut self,
+        name: &str,
+        size: usize,
+        f: &mut F,
+    ) -> PopulationId
+    where
+        F: FnMut(usize) -> M,
+        M: NeuronModel + 'static,
+    {
+        let neurons = self.create_n_neurons_with(size, f);
+        self.add_population(Population::new(name, neurons, None))
+    }
+
+    fn add_population(&mut self, population: Population) -> PopulationId {
+        assert!(self.find_population(&population.name).is_none());
+        self.populations.push(population);
+        PopulationId::from(self.populations.len() - 1)
+    }
+
+    pub fn population(&self, population_id: PopulationId) -> &Population {
+        &self.populations[population_id.index()]
+    }
+
+    pub fn population_mut(&mut self, population_id: PopulationId) -> &mut Population {
+        &mut self.populations[population_id.index()]
+    }
+
+    /// The population named `name`, if any.
+    pub fn find_population(&self, name: &str) -> Option<PopulationId> {
+        self.populations
+            .iter()
+            .position(|p| p.name == name)
+            .map(PopulationId::from)
+    }
+
+    /// Connects the neurons of `pre` to those of `post` with `connector`,
+    /// as a projection named `name`. The `SynapseConfig` of `connector`
+    /// selects the plasticity rule of the synapses.
+    pub fn project(
+        &mut self,
+        name: &str,
+        pre: PopulationId,
+        post: PopulationId,
+        connector: &Connector,
+    ) -> ProjectionId {
+        assert!(self.projections.iter().all(|p| p.name != name));
+        let from_neurons = self.populations[pre.index()].neurons.clone();
+        let to_neurons = self.populations[post.index()].neurons.clone();
+        let synapses = self.connect_topology(&from_neurons, &to_neurons, connector);
+        self.projections.push(Projection {
+            name: name.to_string(),
+            pre,
+            post,
+            connector: *connector,
+            synapses,
+        });
+        ProjectionId::from(self.projections.len() - 1)
+    }
+
+    /// Gives `neurons` an absolute refractory period.
+    pub fn set_refractory_period(
+        &mut self,
+        neurons: &[NeuronId],
+        refractory_period: RefractoryPeriod,
+    ) {
+        for &neuron_id in neurons {
+            self.neurons[neuron_id.index()].refractory_period = Some(refractory_period);
+        }
+    }
+
+    /// Positions `neurons` according to `layout`. As the space is shared by
+    /// all neurons, a `Layout::Torus` requires its space to be selected
+    /// first, with `set_space(layout.space())`.
+    pub fn place(&mut self, neurons: &[NeuronId], layout: Layout) {
+        let space = layout.space();
+        assert!(
+            space == Space::Euclidean || space == self.space,
+            "the layout requires {:?}",
+            space
+        );
+        for (&neuron_id, position) in neurons.iter().zip(layout.positions(neurons.len())) {
+            self.neurons[neuron_id.index()].position = Some(position);
+        }
+    }
+
+    /// Selects the geometry in which distances are measured.
+    pub fn set_space(&mut self, space: Space) {
+        self.space = space;
+    }
+
+    /// The distance between two neurons (in mm), if both have a position.
+    pub fn distance(&self, a: NeuronId, b: NeuronId) -> Option<Num> {
+        match (
+            self.neurons[a.index()].position,
+            self.neurons[b.index()].position,
+        ) {
+            (Some(a), Some(b)) => Some(self.space.distance(a, b)),
+            _ => None,
+        }
+    }
+
     pub fn connect_all(
         &mut self,
         from_neurons: &[NeuronId],
         to_neurons: &[NeuronId],
         delay: SynapseDelay,
         weight: Num,
+        synapse: impl Into<SynapseConfig>,
     ) {
-        self.connect_all_with(from_neurons, to_neurons, &mut |_, _| Some((delay, weight)));
+        self.connect_all_with(from_neurons, to_neurons, synapse, &mut |_, _| {
+            Some((delay, weight))
+        });
     }
 
     pub fn connect_all_with<F>(
         &mut self,
         from_neurons: &[NeuronId],
         to_neurons: &[NeuronId],
+        synapse: impl Into<SynapseConfig>,
         f: &mut F,
     ) where
         F: FnMut(NeuronId, NeuronId) -> Option<(SynapseDelay, Num)>,
     {
+        let synapse = synapse.into();
         for &from in from_neurons {
             for &to in to_neurons {
-                match f(from, to) {
-                    Some((delay, weight)) => {
-                        let _ = self.connect(from, to, delay, weight);
-                    }
-                    None => {}
+                if let Some((delay, weight)) = f(from, to) {
+                    let _ = self.connect(from, to, delay, weight, synapse);
                 }
             }
         }
     }
 
+    /// Connects `from_neurons` to `to_neurons` according to the topology of
+    /// `connector`, drawing weights and delays from its distributions.
+    pub fn connect_topology(
+        &mut self,
+        from_neurons: &[NeuronId],
+        to_neurons: &[NeuronId],
+        connector: &Connector,
+    ) -> Vec<SynapseId> {
+        let mut rng = StdRng::seed_from_u64(connector.seed);
+        connector
+            .topology
+            .generate(from_neurons, to_neurons, &mut rng, |a, b| {
+                self.distance(a, b)
+            })
+            .into_iter()
+            .map(|(pre, post)| {
+                let delay = connector.delays.sample(&mut rng, self.distance(pre, post));
+                let weight = connector.weights.sample(&mut rng);
+                self.connect(pre, post, delay, weight, connector.synapse)
+            })
+            .collect()
+    }
+
+    /// Connects `pre_neuron` with `post_neuron`. `synapse` is either a
+    /// `SynapseConfig`, a `Receptor` or a `CurrentKernel`. The synapse
+    /// targets `SynapseConfig::compartment` of `post_neuron`.
     pub fn connect(
         &mut self,
         pre_neuron: NeuronId,
         post_neuron: NeuronId,
         synapse_delay: SynapseDelay,
         weight: Num,
+        synapse: impl Into<SynapseConfig>,
     ) -> SynapseId {
         assert!(pre_neuron.index() < self.neurons.len());
         assert!(post_neuron.index() < self.neurons.len());
 
-        let synapse = Synapse {
+        let synapse = Synapse::new(
             pre_neuron,
             post_neuron,
             synapse_delay,
             weight,
-            eff_d: 0.0,
-        };
+            synapse.into(),
+        );
+        assert!(synapse.compartment < self.neurons[post_neuron.index()].compartments());
         let synapse_id = SynapseId::from(self.synapses.len());
 
         self.synapses.push(synapse);
@@ -96,13 +259,62 @@ impl NetworkBuilder {
             .pre_synapses
             .push(synapse_id);
 
-        return synapse_id;
+        synapse_id
+    }
+
+    /// Couples `neuron_a` and `neuron_b` electrically. The current flowing
+    /// into either neuron is `conductance` times the difference of their
+    /// potentials, and is applied within the same time step.
+    /// The coupling is explicit, so `conductance` should stay well below
+    /// `1 / dt` of the simulation.
+    pub fn connect_gap_junction(
+        &mut self,
+        neuron_a: NeuronId,
+        neuron_b: NeuronId,
+        conductance: Num,
+    ) {
+        assert!(neuron_a.index() < self.neurons.len());
+        assert!(neuron_b.index() < self.neurons.len());
+        assert!(neuron_a != neuron_b);
+        assert!(conductance >= 0.0);
+
+        self.gap_junctions.push(GapJunction {
+            neurons: (neuron_a, neuron_b),
+            conductance,
+        });
+    }
+
+    /// Couples every pair of distinct `neurons` by a gap junction.
+    pub fn connect_all_gap_junctions(&mut self, neurons: &[NeuronId], conductance: Num) {
+        for (i, &neuron_a) in neurons.iter().enumerate() {
+            for &neuron_b in &neurons[i + 1..] {
+                self.connect_gap_junction(neuron_a, neuron_b, conductance);
+            }
+        }
     }
 
     pub fn into_network(self) -> Network {
-        let NetworkBuilder { neurons, synapses } = self;
+        let NetworkBuilder {
+            neurons,
+            synapses,
+            gap_junctions,
+            space,
+            populations,
+            projections,
+        } = self;
 
-        Network { neurons, synapses }
+        Network {
+            neurons,
+            synapses,
+            gap_junctions,
+            free_neurons: Vec::new(),
+            free_synapses: Vec::new(),
+            space,
+            populations,
+            projections,
+            dopamine: 0.0,
+            dopamine_tau: 200.0,
+        }
     }
 }
 
@@ -1,61 +1,147 @@
-use crate::model::{NeuronActivity, NeuronConfig, NeuronState, StdpConfig};
-use crate::network::SynapseId;
+use crate::model::{Instance, Integrator, ModelInstance, NeuronActivity, NeuronModel};
+use crate::network::synaptic_input::SynapticInput;
+use crate::network::{Position, RefractoryPeriod, SynapseId};
 use crate::Num;
 
 #[derive(Debug)]
 pub struct Neuron {
-    // internal neuron state
-    pub(crate) state: NeuronState,
+    // Neuron model together with its internal state
+    pub(crate) model: Box<dyn ModelInstance>,
 
     // external neuron state
     pub(crate) i_ext: Num,
-    pub(crate) i_inp: Num,
-
-    // learning
-
-    // XXX: Have a list of `active` synapses, which are active
-    // once a neuron fires on it. The `active` synapses track
-    // themselves, once they become inactive. Use a similar
-    // `decay` process as for STDP for the action potential,
-    // so that the shape of the spike is less sharp. A high
-    // decay rate can simulate the old behaviour.
-    //
-    // Spike-Time Dependent Plasticity
-    //
-    // when a neuron fires, we set this value to STDP_FIRE_RESET
-    // (0.1 for example), and during every time-step we decay
-    // it by STDP_DECAY e.g. 0.95.
-    pub(crate) stdp: Num,
-
-    // Neuron model parameters
-    pub(crate) config: NeuronConfig,
+    pub(crate) i_noise: Num,
+    pub(crate) i_gap: Num,
+
+    // synaptic input, one per compartment. The first is the soma.
+    pub(crate) input: Vec<SynapticInput>,
+
+    // the input current of each compartment in the current step
+    i_syn: Vec<Num>,
+
+    // absolute refractory period and the time steps left of it
+    pub(crate) refractory_period: Option<RefractoryPeriod>,
+    pub(crate) refractory_steps: usize,
+
+    // location in space, if the network is spatially embedded
+    pub(crate) position: Option<Position>,
 
     // connectivity
     pub(crate) pre_synapses: Vec<SynapseId>,
     pub(crate) post_synapses: Vec<SynapseId>,
+
+    // generation of the slot, and whether the neuron was removed
+    pub(crate) generation: u32,
+    pub(crate) removed: bool,
 }
 
 impl Neuron {
+    pub(crate) fn new<M: NeuronModel + 'static>(model: M) -> Neuron {
+        let compartments = model.compartments();
+        assert!(compartments >= 1);
+        Neuron {
+            model: Box::new(Instance::new(model)),
+            i_ext: 0.0, // external current
+            i_noise: 0.0,
+            i_gap: 0.0, // current through gap junctions
+            input: vec![SynapticInput::default(); compartments],
+            i_syn: vec![0.0; compartments],
+            refractory_period: None,
+            refractory_steps: 0,
+            position: None,
+            // connectivity
+            pre_synapses: Vec::new(),
+            post_synapses: Vec::new(),
+            generation: 0,
+            removed: false,
+        }
+    }
+
+    /// The membrane potential (in mV).
+    pub fn potential(&self) -> Num {
+        self.model.potential()
+    }
+
+    /// The number of compartments. Compartment 0 is the soma.
+    pub fn compartments(&self) -> usize {
+        self.input.len()
+    }
+
+    /// The membrane potential of `compartment` (in mV).
+    pub fn compartment_potential(&self, compartment: usize) -> Num {
+        self.model.compartment_potential(compartment)
+    }
+
+    /// The neuron's model parameters, if it is a neuron of model `M`.
+    pub fn model<M: NeuronModel + 'static>(&self) -> Option<&M> {
+        self.instance::<M>().map(|instance| &instance.model)
+    }
+
+    /// The neuron's internal state, if it is a neuron of model `M`.
+    pub fn state<M: NeuronModel + 'static>(&self) -> Option<&M::State> {
+        self.instance::<M>().map(|instance| &instance.state)
+    }
+
+    pub fn refractory_period(&self) -> Option<RefractoryPeriod> {
+        self.refractory_period
+    }
+
+    /// After firing, the neuron ignores all input for `refractory_period`.
+    pub fn set_refractory_period(&mut self, refractory_period: Option<RefractoryPeriod>) {
+        self.refractory_period = refractory_period;
+    }
+
+    /// Whether the neuron is within its refractory period. Spikes arriving
+    /// now do not deliver any current, but still take part in plasticity.
+    pub fn is_refractory(&self) -> bool {
+        self.refractory_steps > 0
+    }
+
+    /// The location of the neuron (in mm), if it has one.
+    pub fn position(&self) -> Option<Position> {
+        self.position
+    }
+
+    pub fn set_position(&mut self, position: Option<Position>) {
+        self.position = position;
+    }
+
+    /// The incoming synapses.
+    pub fn pre_synapses(&self) -> &[SynapseId] {
+        &self.pre_synapses
+    }
+
+    /// The outgoing synapses.
+    pub fn post_synapses(&self) -> &[SynapseId] {
+        &self.post_synapses
+    }
+
+    fn instance<M: NeuronModel + 'static>(&self) -> Option<&Instance<M>> {
+        self.model.as_any().downcast_ref::<Instance<M>>()
+    }
+
     // Update the internal neuron state according to the synaptic input.
     // Move into simulator
-    pub fn update_state(&mut self, stdp_config: StdpConfig) -> NeuronActivity {
-        // synaptic input
-        let syn_i = self.i_ext + self.i_inp;
+    pub fn update_state(&mut self, integrator: Integrator, dt: Num) -> NeuronActivity {
+        for (k, input) in self.input.iter_mut().enumerate() {
+            self.i_syn[k] = input.current(self.model.compartment_potential(k), dt);
+        }
+        self.i_syn[0] += self.i_ext + self.i_noise + self.i_gap;
+
+        if self.is_refractory() {
+            // the model keeps evolving, but without any input
+            self.refractory_steps -= 1;
+            self.i_syn.iter_mut().for_each(|i| *i = 0.0);
+        }
 
-        let (new_state, activity) = self.state.step_1ms(syn_i, &self.config);
-        self.state = new_state;
+        let activity = self.model.update(&self.i_syn, dt, integrator);
 
-        match activity {
-            NeuronActivity::Fires => {
-                // Reset the neurons STDP to a high value.
-                self.stdp = stdp_config.fire_reset;
-            }
-            NeuronActivity::Silent => {
-                // decay STDP
-                self.stdp *= stdp_config.decay;
+        if activity.fires() {
+            if let Some(refractory_period) = self.refractory_period {
+                self.refractory_steps = refractory_period.in_steps(dt);
             }
         }
 
-        return activity;
+        activity
     }
 }
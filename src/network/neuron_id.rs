@@ -1,16 +1,37 @@
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
-pub struct NeuronId(u32);
+/// Identifies a neuron of a `Network`. The slot of a removed neuron may be
+/// reused, with the generation increased, so that ids of removed neurons
+/// never refer to another neuron.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct NeuronId {
+    index: u32,
+    generation: u32,
+}
 
 impl NeuronId {
+    #[inline(always)]
+    pub(crate) fn new(index: usize, generation: u32) -> Self {
+        NeuronId {
+            index: index as u32,
+            generation,
+        }
+    }
+
     #[inline(always)]
     pub fn index(&self) -> usize {
-        self.0 as usize
+        self.index as usize
+    }
+
+    /// How often the slot was reused.
+    #[inline(always)]
+    pub fn generation(&self) -> u32 {
+        self.generation
     }
 }
 
 impl From<usize> for NeuronId {
+    /// The id of the neuron in slot `index`, which has never been reused.
     #[inline(always)]
     fn from(index: usize) -> Self {
-        NeuronId(index as u32)
+        NeuronId::new(index, 0)
     }
 }
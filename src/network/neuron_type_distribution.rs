@@ -0,0 +1,52 @@
+use crate::model::NeuronType;
+use crate::{Closed01, Num};
+use rand::Rng;
+
+/// The distribution of the neuron types of a `Population`.
+#[derive(Debug, Clone)]
+pub enum NeuronTypeDistribution {
+    /// Every neuron is of the same type.
+    Constant(NeuronType),
+
+    /// `NeuronType::Excitatory(r)` with `r` uniformly distributed in
+    /// `[0, 1]`, as the excitatory neurons of Izhikevich (2003).
+    Excitatory,
+
+    /// `NeuronType::Inhibitory(r)` with `r` uniformly distributed in
+    /// `[0, 1]`, as the inhibitory neurons of Izhikevich (2003).
+    Inhibitory,
+
+    /// Every neuron is of one of the types, drawn with a probability
+    /// proportional to the number paired with it.
+    Mixture(Vec<(NeuronType, Num)>),
+}
+
+impl NeuronTypeDistribution {
+    pub fn sample<R: Rng>(&self, rng: &mut R) -> NeuronType {
+        match *self {
+            NeuronTypeDistribution::Constant(neuron_type) => neuron_type,
+            NeuronTypeDistribution::Excitatory => NeuronType::Excitatory(Closed01::new(rng.gen())),
+            NeuronTypeDistribution::Inhibitory => NeuronType::Inhibitory(Closed01::new(rng.gen())),
+            NeuronTypeDistribution::Mixture(ref types) => {
+                let total: Num = types.iter().map(|&(_, p)| p).sum();
+                assert!(total > 0.0);
+                let mut x = total * rng.gen::<Num>();
+                for &(neuron_type, p) in types {
+                    assert!(p >= 0.0);
+                    if x < p {
+                        return neuron_type;
+                    }
+                    x -= p;
+                }
+                // rounding errors
+                types.iter().rev().find(|&&(_, p)| p > 0.0).unwrap().0
+            }
+        }
+    }
+}
+
+impl From<NeuronType> for NeuronTypeDistribution {
+    fn from(neuron_type: NeuronType) -> Self {
+        NeuronTypeDistribution::Constant(neuron_type)
+    }
+}
@@ -0,0 +1,78 @@
+use crate::network::{NeuronId, NeuronTypeDistribution};
+use std::collections::BTreeMap;
+
+/// A named group of neurons of a `Network`, created by
+/// `NetworkBuilder::create_population`.
+#[derive(Debug, Clone)]
+pub struct Population {
+    pub(crate) name: String,
+    pub(crate) neurons: Vec<NeuronId>,
+
+    // the distribution the neuron types were drawn from, if any
+    pub(crate) neuron_types: Option<NeuronTypeDistribution>,
+
+    pub(crate) metadata: BTreeMap<String, String>,
+}
+
+impl Population {
+    pub(crate) fn new(
+        name: &str,
+        neurons: Vec<NeuronId>,
+        neuron_types: Option<NeuronTypeDistribution>,
+    ) -> Population {
+        Population {
+            name: name.to_string(),
+            neurons,
+            neuron_types,
+            metadata: BTreeMap::new(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The number of neurons, including any removed since.
+    pub fn size(&self) -> usize {
+        self.neurons.len()
+    }
+
+    pub fn neurons(&self) -> &[NeuronId] {
+        &self.neurons
+    }
+
+    /// The `i`-th neuron of the population.
+    pub fn neuron(&self, i: usize) -> NeuronId {
+        self.neurons[i]
+    }
+
+    /// The position of `neuron_id` within the population, if it belongs to it.
+    pub fn index_of(&self, neuron_id: NeuronId) -> Option<usize> {
+        self.neurons.iter().position(|&n| n == neuron_id)
+    }
+
+    pub fn contains(&self, neuron_id: NeuronId) -> bool {
+        self.index_of(neuron_id).is_some()
+    }
+
+    /// The distribution the neuron types were drawn from, or `None` for
+    /// populations of arbitrary neuron models.
+    pub fn neuron_types(&self) -> Option<&NeuronTypeDistribution> {
+        self.neuron_types.as_ref()
+    }
+
+    pub fn metadata(&self, key: &str) -> Option<&str> {
+        self.metadata.get(key).map(String::as_str)
+    }
+
+    /// All metadata, ordered by key.
+    pub fn metadata_entries(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.metadata
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+
+    pub fn set_metadata(&mut self, key: &str, value: impl Into<String>) {
+        self.metadata.insert(key.to_string(), value.into());
+    }
+}
@@ -0,0 +1,17 @@
+/// Identifies a `Population` of a `Network`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct PopulationId(u32);
+
+impl PopulationId {
+    #[inline(always)]
+    pub fn index(&self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl From<usize> for PopulationId {
+    #[inline(always)]
+    fn from(index: usize) -> Self {
+        PopulationId(index as u32)
+    }
+}
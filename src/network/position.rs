@@ -0,0 +1,19 @@
+use crate::Num;
+
+/// The location of a neuron (in mm).
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct Position {
+    pub x: Num,
+    pub y: Num,
+    pub z: Num,
+}
+
+impl Position {
+    pub fn new(x: Num, y: Num, z: Num) -> Position {
+        Position { x, y, z }
+    }
+
+    pub(crate) fn coordinates(&self) -> [Num; 3] {
+        [self.x, self.y, self.z]
+    }
+}
@@ -0,0 +1,51 @@
+use crate::network::{Connector, PopulationId, SynapseId};
+
+/// The synapses from one `Population` to another, created by
+/// `NetworkBuilder::project`.
+#[derive(Debug, Clone)]
+pub struct Projection {
+    pub(crate) name: String,
+    pub(crate) pre: PopulationId,
+    pub(crate) post: PopulationId,
+    pub(crate) connector: Connector,
+    pub(crate) synapses: Vec<SynapseId>,
+}
+
+impl Projection {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The population of the pre-synaptic neurons.
+    pub fn pre(&self) -> PopulationId {
+        self.pre
+    }
+
+    /// The population of the post-synaptic neurons.
+    pub fn post(&self) -> PopulationId {
+        self.post
+    }
+
+    /// Topology, weight and delay distributions and synapse type of the
+    /// projection.
+    pub fn connector(&self) -> &Connector {
+        &self.connector
+    }
+
+    /// The synapses created by the projection. Some of them may have been
+    /// removed since (see `Network::contains_synapse`).
+    pub fn synapses(&self) -> &[SynapseId] {
+        &self.synapses
+    }
+
+    /// The index of the `Simulator`'s plasticity rule which changes the
+    /// synapses, or `None` if their weights are fixed.
+    pub fn plasticity_rule(&self) -> Option<usize> {
+        let synapse = &self.connector.synapse;
+        if synapse.fixed {
+            None
+        } else {
+            Some(synapse.plasticity_rule)
+        }
+    }
+}
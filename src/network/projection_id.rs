@@ -0,0 +1,17 @@
+/// Identifies a `Projection` of a `Network`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ProjectionId(u32);
+
+impl ProjectionId {
+    #[inline(always)]
+    pub fn index(&self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl From<usize> for ProjectionId {
+    #[inline(always)]
+    fn from(index: usize) -> Self {
+        ProjectionId(index as u32)
+    }
+}
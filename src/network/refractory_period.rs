@@ -0,0 +1,21 @@
+use crate::Num;
+
+/// An absolute refractory period, during which a neuron ignores its input.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum RefractoryPeriod {
+    /// A number of simulation time steps.
+    Steps(usize),
+
+    /// A duration in ms, rounded to time steps.
+    Ms(Num),
+}
+
+impl RefractoryPeriod {
+    /// The period in time steps of `dt` ms.
+    pub fn in_steps(self, dt: Num) -> usize {
+        match self {
+            RefractoryPeriod::Steps(steps) => steps,
+            RefractoryPeriod::Ms(ms) => (ms / dt).round() as usize,
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use crate::network::Position;
+use crate::Num;
+
+/// The geometry in which the distance between neurons is measured.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub enum Space {
+    #[default]
+    Euclidean,
+
+    /// Euclidean space in which each coordinate wraps around at `extent`
+    /// (in mm), so that neurons at opposite borders are neighbours.
+    Torus { extent: [Num; 3] },
+}
+
+impl Space {
+    /// The distance between `a` and `b` (in mm).
+    pub fn distance(&self, a: Position, b: Position) -> Num {
+        let (a, b) = (a.coordinates(), b.coordinates());
+        (0..3)
+            .map(|i| {
+                let d = (a[i] - b[i]).abs();
+                let d = match *self {
+                    Space::Euclidean => d,
+                    Space::Torus { extent } if extent[i] > 0.0 => {
+                        let d = d % extent[i];
+                        d.min(extent[i] - d)
+                    }
+                    Space::Torus { .. } => d,
+                };
+                d * d
+            })
+            .sum::<Num>()
+            .sqrt()
+    }
+}
@@ -1,4 +1,6 @@
-use crate::network::{NeuronId, SynapseDelay};
+use crate::model::short_term_plasticity::StpState;
+use crate::model::{CurrentKernel, Receptor};
+use crate::network::{NeuronId, SynapseConfig, SynapseDelay};
 use crate::Num;
 
 #[derive(Debug)]
@@ -8,7 +10,99 @@ pub struct Synapse {
     pub(crate) post_neuron: NeuronId,
     pub(crate) synapse_delay: SynapseDelay,
     pub(crate) weight: Num,
+    pub(crate) receptor: Receptor,
+    pub(crate) kernel: CurrentKernel,
+    pub(crate) compartment: usize,
+
+    // short-term plasticity
+    pub(crate) stp: Option<StpState>,
+
+    // index of the plasticity rule changing the weight, if any
+    pub(crate) plasticity_rule: Option<usize>,
 
     // efficiacy derivative used for STDP
     pub(crate) eff_d: Num, // ... learning parameters
+
+    // generation of the slot, and whether the synapse was removed
+    pub(crate) generation: u32,
+    pub(crate) removed: bool,
+}
+
+impl Synapse {
+    pub(crate) fn new(
+        pre_neuron: NeuronId,
+        post_neuron: NeuronId,
+        synapse_delay: SynapseDelay,
+        weight: Num,
+        config: SynapseConfig,
+    ) -> Synapse {
+        let SynapseConfig {
+            receptor,
+            kernel,
+            stp,
+            compartment,
+            fixed,
+            plasticity_rule,
+        } = config;
+        // conductance-based synapses follow the time course of their receptor
+        assert!(
+            receptor == Receptor::Current || kernel == CurrentKernel::Pulse,
+            "a current kernel requires Receptor::Current"
+        );
+        Synapse {
+            pre_neuron,
+            post_neuron,
+            synapse_delay,
+            weight,
+            receptor,
+            kernel,
+            compartment,
+            stp: stp.map(StpState::new),
+            plasticity_rule: if fixed { None } else { Some(plasticity_rule) },
+            eff_d: 0.0,
+            generation: 0,
+            removed: false,
+        }
+    }
+
+    pub fn pre_neuron(&self) -> NeuronId {
+        self.pre_neuron
+    }
+
+    pub fn post_neuron(&self) -> NeuronId {
+        self.post_neuron
+    }
+
+    /// The compartment of the post-synaptic neuron the synapse targets.
+    /// Compartment 0 is the soma.
+    pub fn compartment(&self) -> usize {
+        self.compartment
+    }
+
+    /// The index of the `Simulator`'s plasticity rule which changes the
+    /// weight, or `None` if the weight is fixed.
+    pub fn plasticity_rule(&self) -> Option<usize> {
+        self.plasticity_rule
+    }
+
+    pub fn synapse_delay(&self) -> SynapseDelay {
+        self.synapse_delay
+    }
+
+    pub fn weight(&self) -> Num {
+        self.weight
+    }
+
+    pub fn set_weight(&mut self, weight: Num) {
+        self.weight = weight;
+    }
+
+    /// The accumulated weight change, which is not yet applied.
+    pub fn eff_d(&self) -> Num {
+        self.eff_d
+    }
+
+    pub fn set_eff_d(&mut self, eff_d: Num) {
+        self.eff_d = eff_d;
+    }
 }
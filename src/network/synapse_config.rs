@@ -0,0 +1,74 @@
+use crate::model::{CurrentKernel, Receptor, ShortTermPlasticity};
+
+/// The properties of a synapse, which are chosen when connecting two neurons.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct SynapseConfig {
+    /// How the synapse acts on its post-synaptic neuron.
+    pub receptor: Receptor,
+
+    /// The time course of the post-synaptic current of a `Receptor::Current`
+    /// synapse. The weight is the peak current. Other receptors require the
+    /// default, `CurrentKernel::Pulse`.
+    pub kernel: CurrentKernel,
+
+    /// Short-term depression and facilitation of the synapse, if any.
+    pub stp: Option<ShortTermPlasticity>,
+
+    /// The compartment of the post-synaptic neuron the synapse targets.
+    /// The default is 0, the soma.
+    pub compartment: usize,
+
+    /// The weight is not changed by long-term plasticity.
+    pub fixed: bool,
+
+    /// The index of the `Simulator`'s plasticity rule which changes the
+    /// weight (see `Simulator::add_plasticity_rule`). The default is 0.
+    pub plasticity_rule: usize,
+}
+
+impl SynapseConfig {
+    /// Targets `compartment` of the post-synaptic neuron.
+    pub fn at_compartment(self, compartment: usize) -> Self {
+        SynapseConfig {
+            compartment,
+            ..self
+        }
+    }
+
+    /// Excludes the synapse from long-term plasticity.
+    pub fn fixed(self) -> Self {
+        SynapseConfig {
+            fixed: true,
+            ..self
+        }
+    }
+
+    /// Subjects the synapse to the `Simulator`'s plasticity rule
+    /// `plasticity_rule`.
+    pub fn with_plasticity_rule(self, plasticity_rule: usize) -> Self {
+        SynapseConfig {
+            fixed: false,
+            plasticity_rule,
+            ..self
+        }
+    }
+}
+
+impl From<Receptor> for SynapseConfig {
+    fn from(receptor: Receptor) -> Self {
+        SynapseConfig {
+            receptor,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<CurrentKernel> for SynapseConfig {
+    fn from(kernel: CurrentKernel) -> Self {
+        SynapseConfig {
+            receptor: Receptor::Current,
+            kernel,
+            ..Default::default()
+        }
+    }
+}
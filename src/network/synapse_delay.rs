@@ -1,13 +1,44 @@
+use crate::Num;
+
+/// The conduction delay of a synapse. It is expressed in physical time
+/// (with microsecond resolution) and converted into simulation time steps
+/// by the `Simulator`.
 #[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Clone, Copy)]
-pub struct SynapseDelay(u8);
+pub struct SynapseDelay(u32);
+
+const MICROSECONDS_PER_MS: Num = 1000.0;
 
 impl SynapseDelay {
+    /// A delay of `delay` ms.
     pub fn new(delay: u8) -> Self {
+        assert!(delay > 0);
+        Self(delay as u32 * 1000)
+    }
+
+    /// A delay of `delay` ms, rounded to microseconds.
+    pub fn from_ms(delay: Num) -> Self {
+        let us = (delay * MICROSECONDS_PER_MS).round();
+        assert!(us >= 1.0 && us <= u32::MAX as Num);
+        Self(us as u32)
+    }
+
+    pub(crate) fn from_us(delay: u32) -> Self {
         assert!(delay > 0);
         Self(delay)
     }
 
-    pub fn get(self) -> u8 {
+    pub(crate) fn as_us(self) -> u32 {
         self.0
     }
+
+    /// The delay in ms.
+    pub fn as_ms(self) -> Num {
+        self.0 as Num / MICROSECONDS_PER_MS
+    }
+
+    /// The delay in time steps of `dt` ms. A delay is always at least one
+    /// time step long.
+    pub fn in_steps(self, dt: Num) -> usize {
+        ((self.as_ms() / dt).round() as usize).max(1)
+    }
 }
@@ -1,16 +1,37 @@
+/// Identifies a synapse of a `Network`. The slot of a removed synapse may be
+/// reused, with the generation increased, so that ids of removed synapses
+/// never refer to another synapse.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-pub struct SynapseId(u32);
+pub struct SynapseId {
+    index: u32,
+    generation: u32,
+}
 
 impl SynapseId {
+    #[inline(always)]
+    pub(crate) fn new(index: usize, generation: u32) -> Self {
+        SynapseId {
+            index: index as u32,
+            generation,
+        }
+    }
+
     #[inline(always)]
     pub fn index(&self) -> usize {
-        self.0 as usize
+        self.index as usize
+    }
+
+    /// How often the slot was reused.
+    #[inline(always)]
+    pub fn generation(&self) -> u32 {
+        self.generation
     }
 }
 
 impl From<usize> for SynapseId {
+    /// The id of the synapse in slot `index`, which has never been reused.
     #[inline(always)]
     fn from(index: usize) -> Self {
-        SynapseId(index as u32)
+        SynapseId::new(index, 0)
     }
 }
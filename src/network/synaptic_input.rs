@@ -0,0 +1,61 @@
+use crate::model::current_kernel::KernelCurrent;
+use crate::model::receptor::{self, CONDUCTANCES};
+use crate::model::CurrentKernel;
+use crate::Num;
+
+/// The synaptic input into one compartment of a neuron.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SynapticInput {
+    // current of the synaptic pulses arriving in this step
+    pub(crate) i_inp: Num,
+
+    // decaying post-synaptic currents, one per kernel of the
+    // incoming synapses.
+    pub(crate) psc: Vec<KernelCurrent>,
+
+    // conductances of the AMPA, NMDA, GABA_A and GABA_B receptors.
+    pub(crate) g_syn: [Num; CONDUCTANCES],
+}
+
+impl SynapticInput {
+    /// A spike of `weight` arrived at a current-based synapse with `kernel`.
+    pub(crate) fn excite(&mut self, kernel: CurrentKernel, weight: Num) {
+        if kernel == CurrentKernel::Pulse {
+            self.i_inp += weight;
+            return;
+        }
+        match self.psc.iter_mut().find(|psc| psc.kernel == kernel) {
+            Some(psc) => psc.excite(weight),
+            None => {
+                let mut psc = KernelCurrent::new(kernel);
+                psc.excite(weight);
+                self.psc.push(psc);
+            }
+        }
+    }
+
+    /// The synaptic current at `potential`. Advances the kernels and
+    /// conductances by `dt` ms. A synaptic pulse delivers the same charge
+    /// as it does during a 1 ms step, independent of the step size.
+    pub(crate) fn current(&mut self, potential: Num, dt: Num) -> Num {
+        self.i_inp / dt + self.kernel_current(dt) + self.conductance_current(potential, dt)
+    }
+
+    /// The post-synaptic current of the kernels. Advances them by `dt` ms.
+    fn kernel_current(&mut self, dt: Num) -> Num {
+        self.psc.iter_mut().map(|psc| psc.current(dt)).sum()
+    }
+
+    /// The current through the receptor conductances at `potential`.
+    /// Decays the conductances by one time step of `dt` ms.
+    fn conductance_current(&mut self, potential: Num, dt: Num) -> Num {
+        if self.g_syn.iter().all(|&g| g == 0.0) {
+            return 0.0;
+        }
+        let current = receptor::conductance_current(&self.g_syn, potential);
+        for (g, tau) in self.g_syn.iter_mut().zip(receptor::TAU.iter()) {
+            *g *= (-dt / tau).exp();
+        }
+        current
+    }
+}
@@ -0,0 +1,179 @@
+use crate::network::{ConnectionKernel, Layout, NeuronId};
+use crate::Num;
+use rand::seq::index;
+use rand::Rng;
+
+/// A rule which decides which neurons of a source group connect to which
+/// neurons of a target group. No neuron is connected to itself, and no pair
+/// is connected twice.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Topology {
+    /// Every target neuron receives synapses from `k` randomly chosen
+    /// source neurons.
+    FixedInDegree(usize),
+
+    /// Every source neuron projects to `k` randomly chosen target neurons.
+    FixedOutDegree(usize),
+
+    /// Every pair is connected with probability `p` (Erdős–Rényi).
+    ErdosRenyi(Num),
+
+    /// Small-world network of Watts and Strogatz (1998) on a ring: every
+    /// neuron projects to its `k` nearest neighbours (`k / 2` on each side),
+    /// and each of these synapses is rewired to a random target with
+    /// probability `beta`. Source and target group must be the same.
+    WattsStrogatz { k: usize, beta: Num },
+
+    /// Scale-free network of Barabási and Albert (1999): neurons are added
+    /// one by one, each connecting to `m` existing neurons chosen with a
+    /// probability proportional to their degree. Every edge becomes a pair
+    /// of reciprocal synapses. Source and target group must be the same.
+    BarabasiAlbert { m: usize },
+
+    /// The neurons of both groups are arranged by `layout` in the order of
+    /// their index within the group, regardless of their positions in the
+    /// network. A pair is connected with the probability of `kernel` at
+    /// their distance in the space of the layout.
+    Lattice {
+        layout: Layout,
+        kernel: ConnectionKernel,
+    },
+
+    /// A pair is connected with the probability of `kernel` at the distance
+    /// between the positions of the neurons (see `NetworkBuilder::place`).
+    Spatial(ConnectionKernel),
+}
+
+impl Topology {
+    /// The `(pre, post)` pairs to connect, from `from_neurons` to `to_neurons`.
+    /// `distance` returns the distance between two neurons, if known.
+    pub fn generate<R, D>(
+        &self,
+        from_neurons: &[NeuronId],
+        to_neurons: &[NeuronId],
+        rng: &mut R,
+        distance: D,
+    ) -> Vec<(NeuronId, NeuronId)>
+    where
+        R: Rng,
+        D: Fn(NeuronId, NeuronId) -> Option<Num>,
+    {
+        let mut pairs = Vec::new();
+        match *self {
+            Topology::FixedInDegree(k) => {
+                for &post in to_neurons {
+                    let candidates: Vec<NeuronId> = from_neurons
+                        .iter()
+                        .copied()
+                        .filter(|&n| n != post)
+                        .collect();
+                    assert!(k <= candidates.len());
+                    for i in index::sample(rng, candidates.len(), k).into_iter() {
+                        pairs.push((candidates[i], post));
+                    }
+                }
+            }
+            Topology::FixedOutDegree(k) => {
+                for &pre in from_neurons {
+                    let candidates: Vec<NeuronId> =
+                        to_neurons.iter().copied().filter(|&n| n != pre).collect();
+                    assert!(k <= candidates.len());
+                    for i in index::sample(rng, candidates.len(), k).into_iter() {
+                        pairs.push((pre, candidates[i]));
+                    }
+                }
+            }
+            Topology::ErdosRenyi(p) => {
+                assert!((0.0..=1.0).contains(&p));
+                for &pre in from_neurons {
+                    for &post in to_neurons {
+                        if pre != post && rng.gen::<Num>() < p {
+                            pairs.push((pre, post));
+                        }
+                    }
+                }
+            }
+            Topology::WattsStrogatz { k, beta } => {
+                assert_eq!(from_neurons, to_neurons);
+                let neurons = from_neurons;
+                let n = neurons.len();
+                assert!(k % 2 == 0 && k + 1 < n);
+                assert!((0.0..=1.0).contains(&beta));
+                for i in 0..n {
+                    let mut targets: Vec<usize> = (1..=k / 2)
+                        .flat_map(|j| vec![(i + j) % n, (i + n - j) % n])
+                        .collect();
+                    for t in 0..targets.len() {
+                        if rng.gen::<Num>() >= beta {
+                            continue;
+                        }
+                        // rewire to a neuron which is not yet a target
+                        loop {
+                            let target = rng.gen_range(0, n);
+                            if target != i && !targets.contains(&target) {
+                                targets[t] = target;
+                                break;
+                            }
+                        }
+                    }
+                    pairs.extend(targets.into_iter().map(|t| (neurons[i], neurons[t])));
+                }
+            }
+            Topology::BarabasiAlbert { m } => {
+                assert_eq!(from_neurons, to_neurons);
+                let neurons = from_neurons;
+                assert!(m >= 1 && m < neurons.len());
+                // every neuron appears once per edge it is part of, so that
+                // uniform sampling from it is proportional to the degree.
+                let mut endpoints: Vec<usize> = Vec::new();
+                for new in m..neurons.len() {
+                    let mut targets: Vec<usize> = Vec::with_capacity(m);
+                    while targets.len() < m {
+                        let target = if endpoints.is_empty() {
+                            // the first neuron connects to the initial ones
+                            targets.len()
+                        } else {
+                            endpoints[rng.gen_range(0, endpoints.len())]
+                        };
+                        if !targets.contains(&target) {
+                            targets.push(target);
+                        }
+                    }
+                    for target in targets {
+                        endpoints.push(new);
+                        endpoints.push(target);
+                        pairs.push((neurons[new], neurons[target]));
+                        pairs.push((neurons[target], neurons[new]));
+                    }
+                }
+            }
+            Topology::Lattice { layout, kernel } => {
+                assert_eq!(from_neurons.len(), to_neurons.len());
+                let positions = layout.positions(from_neurons.len());
+                let space = layout.space();
+                for (i, &pre) in from_neurons.iter().enumerate() {
+                    for (j, &post) in to_neurons.iter().enumerate() {
+                        let d = space.distance(positions[i], positions[j]);
+                        if pre != post && rng.gen::<Num>() < kernel.probability(d) {
+                            pairs.push((pre, post));
+                        }
+                    }
+                }
+            }
+            Topology::Spatial(kernel) => {
+                for &pre in from_neurons {
+                    for &post in to_neurons {
+                        if pre == post {
+                            continue;
+                        }
+                        let d = distance(pre, post).expect("spatial topology requires positions");
+                        if rng.gen::<Num>() < kernel.probability(d) {
+                            pairs.push((pre, post));
+                        }
+                    }
+                }
+            }
+        }
+        pairs
+    }
+}
@@ -0,0 +1,37 @@
+use crate::Num;
+use rand::distributions::{Distribution, StandardNormal};
+use rand::Rng;
+
+/// The distribution of the weights of generated synapses.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum WeightDistribution {
+    /// Every synapse has the same weight.
+    Constant(Num),
+
+    /// Weights uniformly distributed in `min .. max`.
+    Uniform { min: Num, max: Num },
+
+    /// Normally distributed weights.
+    Normal { mean: Num, std: Num },
+}
+
+impl WeightDistribution {
+    pub fn sample<R: Rng>(&self, rng: &mut R) -> Num {
+        match *self {
+            WeightDistribution::Constant(weight) => weight,
+            WeightDistribution::Uniform { min, max } => {
+                assert!(min <= max);
+                min + (max - min) * rng.gen::<Num>()
+            }
+            WeightDistribution::Normal { mean, std } => {
+                mean + std * StandardNormal.sample(rng) as Num
+            }
+        }
+    }
+}
+
+impl From<Num> for WeightDistribution {
+    fn from(weight: Num) -> Self {
+        WeightDistribution::Constant(weight)
+    }
+}
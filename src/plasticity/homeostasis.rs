@@ -0,0 +1,184 @@
+use crate::model::{ModelInstance, Receptor};
+use crate::network::{Network, NeuronId};
+use crate::plasticity::{state, state_mut, Trace};
+use crate::simulation::Time;
+use crate::Num;
+
+/// The parameter adapted by intrinsic plasticity.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Excitability {
+    /// The firing threshold (see `NeuronModel::shift_threshold`). Not
+    /// supported by models without a threshold, like Hodgkin-Huxley.
+    Threshold,
+
+    /// The spike-triggered adaptation, e.g. `d` of the Izhikevich model
+    /// (see `NeuronModel::increase_adaptation`). Not supported by models
+    /// without adaptation, like LIF and Hodgkin-Huxley.
+    Adaptation,
+}
+
+impl Excitability {
+    /// Whether `model` has the parameter.
+    fn is_supported_by(self, model: &dyn ModelInstance) -> bool {
+        match self {
+            Excitability::Threshold => model.has_threshold(),
+            Excitability::Adaptation => model.has_adaptation(),
+        }
+    }
+}
+
+/// Homeostatic regulation of the firing rates of all neurons towards a
+/// target rate, applied periodically by the `Simulator`.
+///
+/// With synaptic scaling, every update multiplies the excitatory weights onto
+/// a neuron by `1 + scaling_rate * (target_rate - rate) / target_rate`, and
+/// divides its inhibitory weights by the same factor. With intrinsic
+/// plasticity, every update changes the `excitability` parameter of a neuron
+/// by `intrinsic_rate * (rate - target_rate)`.
+#[derive(Debug, Clone)]
+pub struct Homeostasis {
+    /// Firing rate the neurons are driven to (in Hz).
+    pub target_rate: Num,
+
+    /// Time constant of the firing rate estimate (in ms).
+    pub tau_rate: Num,
+
+    /// Relative weight change per update and relative rate error. 0 disables
+    /// synaptic scaling.
+    pub scaling_rate: Num,
+
+    /// The parameter adapted by intrinsic plasticity.
+    pub excitability: Excitability,
+
+    /// Parameter change per update and Hz of rate error. 0 disables
+    /// intrinsic plasticity.
+    pub intrinsic_rate: Num,
+
+    /// firing rate estimate of each neuron (in Hz), with the generation of
+    /// its slot
+    rates: Vec<(u32, Trace)>,
+
+    /// target rates of individual neurons (in Hz), with the generation of
+    /// their slot
+    target_rates: Vec<(u32, Option<Num>)>,
+}
+
+impl Homeostasis {
+    /// Homeostasis towards `target_rate` Hz, with neither synaptic scaling
+    /// nor intrinsic plasticity enabled.
+    pub fn new(target_rate: Num) -> Self {
+        assert!(target_rate > 0.0);
+        Homeostasis {
+            target_rate,
+            tau_rate: 1000.0,
+            scaling_rate: 0.0,
+            excitability: Excitability::Adaptation,
+            intrinsic_rate: 0.0,
+            rates: Vec::new(),
+            target_rates: Vec::new(),
+        }
+    }
+
+    /// Enables multiplicative synaptic scaling.
+    pub fn with_synaptic_scaling(self, scaling_rate: Num) -> Self {
+        Homeostasis {
+            scaling_rate,
+            ..self
+        }
+    }
+
+    /// Enables intrinsic plasticity of `excitability`.
+    pub fn with_intrinsic_plasticity(
+        self,
+        excitability: Excitability,
+        intrinsic_rate: Num,
+    ) -> Self {
+        Homeostasis {
+            excitability,
+            intrinsic_rate,
+            ..self
+        }
+    }
+
+    /// Overrides the target rate of `neuron` (in Hz).
+    pub fn set_target_rate(&mut self, neuron: NeuronId, target_rate: Num) {
+        assert!(target_rate > 0.0);
+        *state_mut(&mut self.target_rates, neuron) = Some(target_rate);
+    }
+
+    pub fn target_rate_of(&self, neuron: NeuronId) -> Num {
+        match state(&self.target_rates, neuron) {
+            Some(&Some(target_rate)) => target_rate,
+            _ => self.target_rate,
+        }
+    }
+
+    /// The estimated firing rate of `neuron` at `time` (in Hz).
+    pub fn firing_rate(&self, neuron: NeuronId, time: Time) -> Num {
+        state(&self.rates, neuron).map_or(0.0, |rate| rate.at(time, self.tau_rate))
+    }
+
+    pub(crate) fn record_spike(&mut self, neuron: NeuronId, time: Time) {
+        let tau_rate = self.tau_rate;
+        state_mut(&mut self.rates, neuron).add(time, tau_rate, 1000.0 / tau_rate);
+    }
+
+    /// Panics if intrinsic plasticity is enabled, but the model of a neuron
+    /// of `network` lacks the `excitability` parameter.
+    pub fn check(&self, network: &Network) {
+        if self.intrinsic_rate == 0.0 {
+            return;
+        }
+        for neuron_id in network.neuron_ids() {
+            let model = &*network.neurons[neuron_id.index()].model;
+            assert!(
+                self.excitability.is_supported_by(model),
+                "intrinsic plasticity of {:?} is not supported by neuron {}: {:?}",
+                self.excitability,
+                neuron_id.index(),
+                model
+            );
+        }
+    }
+
+    /// Regulates all neurons of `network` according to their firing rate at `time`.
+    /// Checks all neurons first (see `check`).
+    pub fn update(&mut self, network: &mut Network, time: Time) {
+        self.check(network);
+        for i in 0..network.neurons.len() {
+            if network.neurons[i].removed {
+                continue;
+            }
+            let neuron_id = NeuronId::new(i, network.neurons[i].generation);
+            let rate = self.firing_rate(neuron_id, time);
+            let target_rate = self.target_rate_of(neuron_id);
+
+            if self.scaling_rate != 0.0 {
+                let factor =
+                    (1.0 + self.scaling_rate * (target_rate - rate) / target_rate).max(0.0);
+                let neuron = &network.neurons[i];
+                for &synapse_id in neuron.pre_synapses.iter() {
+                    let synapse = &mut network.synapses[synapse_id.index()];
+                    let inhibitory = match synapse.receptor {
+                        Receptor::GabaA | Receptor::GabaB => true,
+                        _ => synapse.weight < 0.0,
+                    };
+                    if !inhibitory {
+                        synapse.weight *= factor;
+                    } else if factor > 0.0 {
+                        synapse.weight /= factor;
+                    }
+                }
+            }
+
+            if self.intrinsic_rate != 0.0 {
+                let delta = self.intrinsic_rate * (rate - target_rate);
+                let model = &mut network.neurons[i].model;
+                match self.excitability {
+                    Excitability::Threshold => model.shift_threshold(delta),
+                    Excitability::Adaptation => model.increase_adaptation(delta),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,19 @@
+pub mod homeostasis;
+pub mod pair_stdp;
+pub mod plasticity_rule;
+pub mod reward_stdp;
+pub mod spnet_stdp;
+pub mod structural_plasticity;
+pub mod trace;
+pub mod triplet_stdp;
+pub mod turnover;
+
+pub use homeostasis::{Excitability, Homeostasis};
+pub use pair_stdp::{PairStdp, WeightDependence};
+pub use plasticity_rule::PlasticityRule;
+pub use reward_stdp::RewardStdp;
+pub use spnet_stdp::SpnetStdp;
+pub use structural_plasticity::StructuralPlasticity;
+pub(crate) use trace::{element_mut, state, state_mut, Trace};
+pub use triplet_stdp::TripletStdp;
+pub use turnover::Turnover;
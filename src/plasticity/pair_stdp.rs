@@ -0,0 +1,132 @@
+use crate::network::{Network, NeuronId, SynapseId};
+use crate::plasticity::{state_mut, PlasticityRule, Trace};
+use crate::simulation::Time;
+use crate::Num;
+
+/// How the weight change of `PairStdp` depends on the current weight.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum WeightDependence {
+    /// The weight changes independently of the weight and is clamped to
+    /// the bounds (hard bounds).
+    Additive,
+
+    /// Potentiation is proportional to `max_weight - weight` and depression
+    /// to `weight - min_weight`, so that the weight approaches the bounds
+    /// smoothly (soft bounds).
+    Multiplicative,
+}
+
+/// Pair-based STDP with all-to-all spike interactions.
+///
+/// Each synapse keeps a trace of the arriving pre-synaptic spikes, decaying
+/// with `tau_plus`, and each neuron a trace of its own spikes, decaying with
+/// `tau_minus`. A post-synaptic spike potentiates the synapse by `a_plus`
+/// times the pre-synaptic trace, and an arriving pre-synaptic spike depresses
+/// it by `a_minus` times the post-synaptic trace.
+#[derive(Debug, Clone)]
+pub struct PairStdp {
+    /// Amplitude of potentiation.
+    pub a_plus: Num,
+
+    /// Amplitude of depression.
+    pub a_minus: Num,
+
+    /// Time constant of the pre-synaptic trace (in ms).
+    pub tau_plus: Num,
+
+    /// Time constant of the post-synaptic trace (in ms).
+    pub tau_minus: Num,
+
+    pub weight_dependence: WeightDependence,
+
+    /// Lower bound of the weights.
+    pub min_weight: Num,
+
+    /// Upper bound of the weights.
+    pub max_weight: Num,
+
+    /// pre-synaptic trace of each synapse, with the generation of its slot
+    pre_traces: Vec<(u32, Trace)>,
+
+    /// post-synaptic trace of each neuron, with the generation of its slot
+    post_traces: Vec<(u32, Trace)>,
+}
+
+impl PairStdp {
+    /// Additive STDP with weights bounded to `[0, 10]`.
+    pub fn additive(a_plus: Num, a_minus: Num, tau_plus: Num, tau_minus: Num) -> Self {
+        assert!(tau_plus > 0.0 && tau_minus > 0.0);
+        PairStdp {
+            a_plus,
+            a_minus,
+            tau_plus,
+            tau_minus,
+            weight_dependence: WeightDependence::Additive,
+            min_weight: 0.0,
+            max_weight: 10.0,
+            pre_traces: Vec::new(),
+            post_traces: Vec::new(),
+        }
+    }
+
+    /// Multiplicative (soft-bounded) STDP with weights bounded to `[0, 10]`.
+    pub fn multiplicative(a_plus: Num, a_minus: Num, tau_plus: Num, tau_minus: Num) -> Self {
+        PairStdp {
+            weight_dependence: WeightDependence::Multiplicative,
+            ..Self::additive(a_plus, a_minus, tau_plus, tau_minus)
+        }
+    }
+
+    /// Bounds the weights to `[min_weight, max_weight]`.
+    pub fn with_bounds(self, min_weight: Num, max_weight: Num) -> Self {
+        assert!(min_weight <= max_weight);
+        PairStdp {
+            min_weight,
+            max_weight,
+            ..self
+        }
+    }
+
+    fn potentiation(&self, weight: Num) -> Num {
+        match self.weight_dependence {
+            WeightDependence::Additive => self.a_plus,
+            WeightDependence::Multiplicative => self.a_plus * (self.max_weight - weight),
+        }
+    }
+
+    fn depression(&self, weight: Num) -> Num {
+        match self.weight_dependence {
+            WeightDependence::Additive => self.a_minus,
+            WeightDependence::Multiplicative => self.a_minus * (weight - self.min_weight),
+        }
+    }
+}
+
+impl PlasticityRule for PairStdp {
+    fn pre_spike(&mut self, network: &mut Network, synapse: SynapseId, time: Time) {
+        let syn = &mut network.synapses[synapse.index()];
+
+        let post_trace = state_mut(&mut self.post_traces, syn.post_neuron);
+        let o = post_trace.at(time, self.tau_minus);
+        syn.eff_d -= self.depression(syn.weight) * o;
+
+        state_mut(&mut self.pre_traces, synapse).add(time, self.tau_plus, 1.0);
+    }
+
+    fn post_spike(&mut self, network: &mut Network, rule: usize, neuron: NeuronId, time: Time) {
+        for &synapse_id in network.neurons[neuron.index()].pre_synapses.iter() {
+            if !network.is_plastic(synapse_id, rule) {
+                continue;
+            }
+            let synapse = &mut network.synapses[synapse_id.index()];
+            let r = state_mut(&mut self.pre_traces, synapse_id).at(time, self.tau_plus);
+            synapse.eff_d += self.potentiation(synapse.weight) * r;
+        }
+
+        state_mut(&mut self.post_traces, neuron).add(time, self.tau_minus, 1.0);
+    }
+
+    fn update_weights(&mut self, network: &mut Network, rule: usize, _time: Time) {
+        network.update_rule_weights(rule, self.min_weight, self.max_weight, 0.0);
+    }
+}
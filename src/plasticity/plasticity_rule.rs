@@ -0,0 +1,30 @@
+use crate::network::{Network, NeuronId, SynapseId};
+use crate::simulation::Time;
+use crate::Num;
+use std::fmt::Debug;
+
+/// A rule for long-term synaptic plasticity, driven by the `Simulator`.
+///
+/// Rules typically accumulate weight changes in a synapse's `eff_d` on
+/// every spike and apply them in `update_weights`, which is called
+/// periodically.
+///
+/// A rule only changes the synapses assigned to it by
+/// `SynapseConfig::plasticity_rule`. `rule` is the index of the rule in the
+/// `Simulator` (see `Simulator::add_plasticity_rule`); `post_spike` and
+/// weight updates skip the synapses of other rules.
+pub trait PlasticityRule: Debug {
+    /// A spike of its pre-synaptic neuron arrives at `synapse`, which is
+    /// assigned to this rule, at `time` (in ms).
+    fn pre_spike(&mut self, network: &mut Network, synapse: SynapseId, time: Time);
+
+    /// `neuron` fires at `time` (in ms).
+    fn post_spike(&mut self, network: &mut Network, rule: usize, neuron: NeuronId, time: Time);
+
+    /// Called after every time step of `dt` ms, for rules whose weights
+    /// change continuously.
+    fn step(&mut self, _network: &mut Network, _rule: usize, _time: Time, _dt: Num) {}
+
+    /// Apply the accumulated weight changes.
+    fn update_weights(&mut self, network: &mut Network, rule: usize, time: Time);
+}
@@ -0,0 +1,65 @@
+use crate::network::{Network, NeuronId, SynapseId};
+use crate::plasticity::{PairStdp, PlasticityRule};
+use crate::simulation::Time;
+use crate::Num;
+
+/// Dopamine-modulated STDP of Izhikevich (2007), "Solving the distal reward
+/// problem through linkage of STDP and dopamine signaling".
+///
+/// The weight changes of `stdp` are not applied directly, but accumulate in
+/// an eligibility trace `c` of each synapse (its `eff_d`), which decays with
+/// `tau_c`. The weights change continuously by `dw/dt = c * d`, where `d` is
+/// the dopamine concentration of the network (see `Network::deliver_reward`
+/// and `Network::dopamine_time_constant`).
+#[derive(Debug, Clone)]
+pub struct RewardStdp {
+    /// The STDP rule which drives the eligibility traces. Its bounds apply
+    /// to the weights.
+    pub stdp: PairStdp,
+
+    /// Time constant of the eligibility traces (in ms).
+    pub tau_c: Num,
+}
+
+impl Default for RewardStdp {
+    /// The time constants of Izhikevich (2007), with weights bounded to
+    /// `[0, 4]`. The STDP amplitudes keep the ratio of 1 : 1.5, but are
+    /// scaled so that a reward of 0.5 changes a synapse which took part in a
+    /// pairing one second earlier by about 0.1.
+    fn default() -> Self {
+        RewardStdp {
+            stdp: PairStdp::additive(0.005, 0.0075, 20.0, 20.0).with_bounds(0.0, 4.0),
+            tau_c: 1000.0,
+        }
+    }
+}
+
+impl PlasticityRule for RewardStdp {
+    fn pre_spike(&mut self, network: &mut Network, synapse: SynapseId, time: Time) {
+        self.stdp.pre_spike(network, synapse, time);
+    }
+
+    fn post_spike(&mut self, network: &mut Network, rule: usize, neuron: NeuronId, time: Time) {
+        self.stdp.post_spike(network, rule, neuron, time);
+    }
+
+    fn step(&mut self, network: &mut Network, rule: usize, _time: Time, dt: Num) {
+        let d = network.dopamine;
+        let c_decay = (-dt / self.tau_c).exp();
+        let (min_weight, max_weight) = (self.stdp.min_weight, self.stdp.max_weight);
+
+        for syn in network
+            .synapses
+            .iter_mut()
+            .filter(|syn| !syn.removed && syn.plasticity_rule == Some(rule))
+        {
+            if d != 0.0 {
+                syn.weight = (syn.weight + syn.eff_d * d * dt).clamp(min_weight, max_weight);
+            }
+            syn.eff_d *= c_decay;
+        }
+    }
+
+    /// The weights are already updated in every time step.
+    fn update_weights(&mut self, _network: &mut Network, _rule: usize, _time: Time) {}
+}
@@ -0,0 +1,130 @@
+use crate::model::StdpConfig;
+use crate::network::{Network, NeuronId, SynapseId};
+use crate::plasticity::{element_mut, state, state_mut, PlasticityRule};
+use crate::simulation::Time;
+use crate::Num;
+
+/// The STDP rule of Izhikevich's `spnet`.
+///
+/// Every neuron has a trace, which is reset to `fire_reset` in the step the
+/// neuron fires and decays by `decay` per millisecond otherwise. When a
+/// neuron fires, its incoming synapses are potentiated by the trace of their
+/// pre-synaptic neuron. When a spike arrives at a synapse, it is changed by
+/// the trace of its pre-synaptic minus that of its post-synaptic neuron.
+///
+/// The traces are updated in the same order as in `spnet`: spikes arriving
+/// in a step see the traces of the previous step, and a firing neuron sees
+/// the traces of the neurons updated before it in the same step.
+#[derive(Debug, Clone)]
+pub struct SpnetStdp {
+    pub config: StdpConfig,
+
+    /// Lower bound of the weights.
+    pub min_weight: Num,
+
+    /// Upper bound of the weights.
+    pub max_weight: Num,
+
+    /// The factor by which `eff_d` decays on every weight update.
+    pub eff_d_decay: Num,
+
+    /// the trace of each neuron, as of the end of the previous step, with
+    /// the generation of its slot
+    traces: Vec<(u32, Num)>,
+
+    /// whether each neuron fired in the current step
+    fired: Vec<bool>,
+
+    /// duration of a time step (in ms)
+    dt: Num,
+}
+
+impl From<StdpConfig> for SpnetStdp {
+    fn from(config: StdpConfig) -> Self {
+        SpnetStdp {
+            config,
+            min_weight: 0.0,
+            max_weight: 10.0,
+            eff_d_decay: 0.9,
+            traces: Vec::new(),
+            fired: Vec::new(),
+            dt: 1.0,
+        }
+    }
+}
+
+impl Default for SpnetStdp {
+    fn default() -> Self {
+        Self::from(StdpConfig::default())
+    }
+}
+
+impl SpnetStdp {
+    /// The trace of `neuron` as of the end of the previous step.
+    fn trace(&self, neuron: NeuronId) -> Num {
+        state(&self.traces, neuron).cloned().unwrap_or(0.0)
+    }
+
+    /// The trace of `neuron` after the current step.
+    fn updated_trace(&self, neuron: NeuronId) -> Num {
+        if self.fired.get(neuron.index()).cloned().unwrap_or(false) {
+            self.config.fire_reset
+        } else {
+            self.trace(neuron) * self.config.decay.powf(self.dt)
+        }
+    }
+}
+
+impl PlasticityRule for SpnetStdp {
+    fn pre_spike(&mut self, network: &mut Network, synapse: SynapseId, _time: Time) {
+        let (pre_trace, post_trace) = {
+            let syn = &network.synapses[synapse.index()];
+            (self.trace(syn.pre_neuron), self.trace(syn.post_neuron))
+        };
+
+        // whenever a spike arrives here at it's post_neuron, this means, that
+        // the pre-neuron fired some time ago (delay time-steps). It can be the
+        // case that the post_neuron has fired ealier, in which case we have to
+        // depress the synapse according to the STDP rule.
+        network.synapses[synapse.index()].eff_d += pre_trace - post_trace;
+    }
+
+    // Excite the synapses that might have led to the firing of the neuron.
+    // We do this by adding the synapses pre_neuron's STDP value to the
+    // synapses eff_d (efficacy derivative) value.
+    fn post_spike(&mut self, network: &mut Network, rule: usize, neuron: NeuronId, _time: Time) {
+        *element_mut(&mut self.fired, neuron.index()) = true;
+
+        for &synapse_id in network.neurons[neuron.index()].pre_synapses.iter() {
+            if !network.is_plastic(synapse_id, rule) {
+                continue;
+            }
+            let synapse = &mut network.synapses[synapse_id.index()];
+            // neurons up to this one have already been updated in this step
+            synapse.eff_d += if synapse.pre_neuron.index() <= neuron.index() {
+                self.updated_trace(synapse.pre_neuron)
+            } else {
+                self.trace(synapse.pre_neuron)
+            };
+        }
+    }
+
+    fn step(&mut self, network: &mut Network, _rule: usize, _time: Time, dt: Num) {
+        self.dt = dt;
+        self.fired.resize(network.neurons.len(), false);
+        let decay = self.config.decay.powf(dt);
+        for (i, neuron) in network.neurons.iter().enumerate() {
+            let trace = state_mut(&mut self.traces, NeuronId::new(i, neuron.generation));
+            if self.fired[i] {
+                *trace = self.config.fire_reset;
+                self.fired[i] = false;
+            } else {
+                *trace *= decay;
+            }
+        }
+    }
+
+    fn update_weights(&mut self, network: &mut Network, rule: usize, _time: Time) {
+        network.update_rule_weights(rule, self.min_weight, self.max_weight, self.eff_d_decay);
+    }
+}
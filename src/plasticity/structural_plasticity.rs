@@ -0,0 +1,235 @@
+use crate::network::{DelayDistribution, Network, NeuronId, SynapseConfig, SynapseDelay};
+use crate::plasticity::{element_mut, Turnover};
+use crate::simulation::Time;
+use crate::Num;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::{HashSet, VecDeque};
+
+/// Activity-dependent creation and pruning of synapses, applied periodically
+/// by the `Simulator`.
+///
+/// Every update prunes the synapses whose absolute weight stayed below
+/// `prune_threshold` for at least `prune_delay` ms, and grows a synapse with
+/// `growth_probability` for every pair of neurons which fired together since
+/// the last update: the pre-synaptic neuron at most `coactivity_window` ms
+/// before the post-synaptic one. Neurons which are already connected are not
+/// connected again.
+#[derive(Debug, Clone)]
+pub struct StructuralPlasticity {
+    /// Synapses with a smaller absolute weight are candidates for pruning.
+    /// 0 disables pruning.
+    pub prune_threshold: Num,
+
+    /// How long the weight has to stay below `prune_threshold` (in ms).
+    pub prune_delay: Num,
+
+    /// Probability per update to connect a pair of co-active neurons.
+    /// 0 disables growth.
+    pub growth_probability: Num,
+
+    /// Maximum time between the spikes of co-active neurons (in ms).
+    pub coactivity_window: Num,
+
+    /// Weight of grown synapses.
+    pub initial_weight: Num,
+
+    /// Delays of grown synapses.
+    pub delays: DelayDistribution,
+
+    /// Longer delays sampled from `delays` are clamped to this one, if any.
+    pub max_delay: Option<SynapseDelay>,
+
+    /// Receptor, kernel and short-term plasticity of grown synapses.
+    pub synapse: SynapseConfig,
+
+    /// No synapses are grown onto neurons with this many incoming synapses.
+    pub max_in_degree: Option<usize>,
+
+    rng: StdRng,
+
+    /// spikes within the last `coactivity_window` ms
+    recent_spikes: VecDeque<(NeuronId, Time)>,
+
+    /// pairs of co-active neurons since the last update
+    coactive: HashSet<(NeuronId, NeuronId)>,
+
+    /// time since which the weight of each synapse slot is below the
+    /// threshold, with the generation of the synapse
+    below_threshold: Vec<Option<(u32, Time)>>,
+
+    history: Vec<Turnover>,
+}
+
+impl StructuralPlasticity {
+    /// Structural plasticity with neither pruning nor growth enabled. Random
+    /// decisions use a generator seeded with `seed`.
+    pub fn new(seed: u64) -> Self {
+        StructuralPlasticity {
+            prune_threshold: 0.0,
+            prune_delay: 1000.0,
+            growth_probability: 0.0,
+            coactivity_window: 20.0,
+            initial_weight: 1.0,
+            delays: DelayDistribution::Constant(SynapseDelay::new(1)),
+            max_delay: None,
+            synapse: SynapseConfig::default(),
+            max_in_degree: None,
+            rng: StdRng::seed_from_u64(seed),
+            recent_spikes: VecDeque::new(),
+            coactive: HashSet::new(),
+            below_threshold: Vec::new(),
+            history: Vec::new(),
+        }
+    }
+
+    /// Enables pruning of synapses weaker than `threshold` for `delay` ms.
+    pub fn with_pruning(self, threshold: Num, delay: Num) -> Self {
+        assert!(threshold >= 0.0 && delay >= 0.0);
+        StructuralPlasticity {
+            prune_threshold: threshold,
+            prune_delay: delay,
+            ..self
+        }
+    }
+
+    /// Enables growth of synapses of `weight` between co-active neurons.
+    pub fn with_growth(
+        self,
+        probability: Num,
+        weight: Num,
+        delays: impl Into<DelayDistribution>,
+    ) -> Self {
+        assert!((0.0..=1.0).contains(&probability));
+        StructuralPlasticity {
+            growth_probability: probability,
+            initial_weight: weight,
+            delays: delays.into(),
+            ..self
+        }
+    }
+
+    /// Clamps the delays of grown synapses to `max_delay`.
+    pub fn with_max_delay(self, max_delay: SynapseDelay) -> Self {
+        StructuralPlasticity {
+            max_delay: Some(max_delay),
+            ..self
+        }
+    }
+
+    /// The turnover of every update so far.
+    pub fn history(&self) -> &[Turnover] {
+        &self.history
+    }
+
+    /// The number of synapses created and pruned by all updates so far.
+    pub fn total_turnover(&self) -> (usize, usize) {
+        self.history
+            .iter()
+            .fold((0, 0), |(c, p), t| (c + t.created, p + t.pruned))
+    }
+
+    pub(crate) fn record_spike(&mut self, neuron: NeuronId, time: Time) {
+        if self.growth_probability == 0.0 {
+            return;
+        }
+        let window = self.coactivity_window as Time;
+        while let Some(&(_, t)) = self.recent_spikes.front() {
+            if time - t <= window {
+                break;
+            }
+            self.recent_spikes.pop_front();
+        }
+        for &(pre, _) in self.recent_spikes.iter() {
+            if pre != neuron {
+                self.coactive.insert((pre, neuron));
+            }
+        }
+        self.recent_spikes.push_back((neuron, time));
+    }
+
+    /// Prunes and grows the synapses of `network` at `time`.
+    pub fn update(&mut self, network: &mut Network, time: Time) -> Turnover {
+        let pruned = self.prune(network, time);
+        let created = self.grow(network);
+        let turnover = Turnover {
+            time,
+            created,
+            pruned,
+            synapses: network.total_synapses(),
+        };
+        self.history.push(turnover);
+        turnover
+    }
+
+    fn prune(&mut self, network: &mut Network, time: Time) -> usize {
+        if self.prune_threshold == 0.0 {
+            return 0;
+        }
+        let synapse_ids: Vec<_> = network.synapse_ids().collect();
+        let mut pruned = 0;
+        for synapse_id in synapse_ids {
+            let weak = network.synapse_ref(synapse_id).weight().abs() < self.prune_threshold;
+            let since = element_mut(&mut self.below_threshold, synapse_id.index());
+            *since = match (*since, weak) {
+                (_, false) => None,
+                (Some((generation, t)), true) if generation == synapse_id.generation() => {
+                    Some((generation, t))
+                }
+                (_, true) => Some((synapse_id.generation(), time)),
+            };
+            if let Some((_, t)) = *since {
+                if time - t >= self.prune_delay as Time {
+                    network.disconnect(synapse_id);
+                    *since = None;
+                    pruned += 1;
+                }
+            }
+        }
+        pruned
+    }
+
+    fn grow(&mut self, network: &mut Network) -> usize {
+        // in a fixed order, for reproducible random decisions
+        let mut coactive: Vec<_> = self.coactive.drain().collect();
+        coactive.sort_by_key(|&(pre, post)| {
+            (
+                pre.index(),
+                pre.generation(),
+                post.index(),
+                post.generation(),
+            )
+        });
+
+        let mut created = 0;
+        for (pre, post) in coactive {
+            if !network.contains_neuron(pre) || !network.contains_neuron(post) {
+                continue;
+            }
+            let post_neuron = network.neuron_ref(post);
+            if self
+                .max_in_degree
+                .is_some_and(|max| post_neuron.pre_synapses().len() >= max)
+            {
+                continue;
+            }
+            let connected = post_neuron
+                .pre_synapses()
+                .iter()
+                .any(|&s| network.synapse_ref(s).pre_neuron() == pre);
+            if connected || self.rng.gen::<Num>() >= self.growth_probability {
+                continue;
+            }
+            let delay = self
+                .delays
+                .sample(&mut self.rng, network.distance(pre, post));
+            let delay = match self.max_delay {
+                Some(max_delay) => delay.min(max_delay),
+                None => delay,
+            };
+            network.connect(pre, post, delay, self.initial_weight, self.synapse);
+            created += 1;
+        }
+        created
+    }
+}
@@ -0,0 +1,87 @@
+use crate::network::{NeuronId, SynapseId};
+use crate::simulation::Time;
+use crate::Num;
+
+/// An exponentially decaying trace of spikes. The decay is only evaluated
+/// when the trace is accessed.
+#[derive(Debug, Copy, Clone, Default)]
+pub(crate) struct Trace {
+    value: Num,
+
+    /// time of the last update (in ms)
+    time: Time,
+}
+
+impl Trace {
+    /// The value at `time`, for a time constant of `tau` ms.
+    #[inline]
+    pub(crate) fn at(&self, time: Time, tau: Num) -> Num {
+        if self.value == 0.0 {
+            return 0.0;
+        }
+        self.value * (-((time - self.time) as Num) / tau).exp()
+    }
+
+    /// Adds `amount` at `time`.
+    #[inline]
+    pub(crate) fn add(&mut self, time: Time, tau: Num, amount: Num) {
+        self.value = self.at(time, tau) + amount;
+        self.time = time;
+    }
+}
+
+/// The element at `index`, growing `elements` as needed.
+#[inline]
+pub(crate) fn element_mut<T: Default + Clone>(elements: &mut Vec<T>, index: usize) -> &mut T {
+    if index >= elements.len() {
+        elements.resize(index + 1, T::default());
+    }
+    &mut elements[index]
+}
+
+/// The id of a neuron or synapse, whose slot may be reused by a later
+/// generation.
+pub(crate) trait Slot: Copy {
+    fn index(&self) -> usize;
+    fn generation(&self) -> u32;
+}
+
+impl Slot for NeuronId {
+    fn index(&self) -> usize {
+        NeuronId::index(self)
+    }
+
+    fn generation(&self) -> u32 {
+        NeuronId::generation(self)
+    }
+}
+
+impl Slot for SynapseId {
+    fn index(&self) -> usize {
+        SynapseId::index(self)
+    }
+
+    fn generation(&self) -> u32 {
+        SynapseId::generation(self)
+    }
+}
+
+/// The state kept for `id`, growing `states` as needed. State left by an
+/// earlier generation of the slot is reset to the default.
+#[inline]
+pub(crate) fn state_mut<T: Default + Clone>(states: &mut Vec<(u32, T)>, id: impl Slot) -> &mut T {
+    let state = element_mut(states, id.index());
+    if state.0 != id.generation() {
+        *state = (id.generation(), T::default());
+    }
+    &mut state.1
+}
+
+/// The state kept for `id`, unless there is none.
+#[inline]
+pub(crate) fn state<T>(states: &[(u32, T)], id: impl Slot) -> Option<&T> {
+    match states.get(id.index()) {
+        Some((generation, state)) if *generation == id.generation() => Some(state),
+        _ => None,
+    }
+}
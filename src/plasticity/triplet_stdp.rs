@@ -0,0 +1,119 @@
+use crate::network::{Network, NeuronId, SynapseId};
+use crate::plasticity::{state_mut, PlasticityRule, Trace};
+use crate::simulation::Time;
+use crate::Num;
+
+/// Triplet STDP of Pfister and Gerstner (2006) with all-to-all spike
+/// interactions.
+///
+/// Each synapse keeps two traces `r1` and `r2` of the arriving pre-synaptic
+/// spikes, and each neuron two traces `o1` and `o2` of its own spikes. A
+/// post-synaptic spike potentiates the synapse by `r1 * (a2_plus + a3_plus * o2)`
+/// and an arriving pre-synaptic spike depresses it by
+/// `o1 * (a2_minus + a3_minus * r2)`, where the traces of the spiking side
+/// are taken just before the spike.
+///
+/// The amplitudes of the presets are for weights of the order of 1 and
+/// have to be scaled together with the weights.
+#[derive(Debug, Clone)]
+pub struct TripletStdp {
+    /// Amplitude of pair potentiation.
+    pub a2_plus: Num,
+
+    /// Amplitude of triplet potentiation.
+    pub a3_plus: Num,
+
+    /// Amplitude of pair depression.
+    pub a2_minus: Num,
+
+    /// Amplitude of triplet depression.
+    pub a3_minus: Num,
+
+    /// Time constant of `r1` (in ms).
+    pub tau_plus: Num,
+
+    /// Time constant of `o1` (in ms).
+    pub tau_minus: Num,
+
+    /// Time constant of `r2` (in ms).
+    pub tau_x: Num,
+
+    /// Time constant of `o2` (in ms).
+    pub tau_y: Num,
+
+    /// Lower bound of the weights.
+    pub min_weight: Num,
+
+    /// Upper bound of the weights.
+    pub max_weight: Num,
+
+    /// traces `r1` and `r2` of each synapse, with the generation of its slot
+    pre_traces: Vec<(u32, [Trace; 2])>,
+
+    /// traces `o1` and `o2` of each neuron, with the generation of its slot
+    post_traces: Vec<(u32, [Trace; 2])>,
+}
+
+impl TripletStdp {
+    /// The all-to-all model fitted to hippocampal data (Pfister and
+    /// Gerstner, 2006), with weights bounded to `[0, 1]`.
+    pub fn hippocampal() -> Self {
+        TripletStdp {
+            a2_plus: 5.0e-10,
+            a3_plus: 6.2e-3,
+            a2_minus: 7.0e-3,
+            a3_minus: 2.3e-4,
+            tau_plus: 16.8,
+            tau_minus: 33.7,
+            tau_x: 101.0,
+            tau_y: 125.0,
+            min_weight: 0.0,
+            max_weight: 1.0,
+            pre_traces: Vec::new(),
+            post_traces: Vec::new(),
+        }
+    }
+
+    /// Bounds the weights to `[min_weight, max_weight]`.
+    pub fn with_bounds(self, min_weight: Num, max_weight: Num) -> Self {
+        assert!(min_weight <= max_weight);
+        TripletStdp {
+            min_weight,
+            max_weight,
+            ..self
+        }
+    }
+}
+
+impl PlasticityRule for TripletStdp {
+    fn pre_spike(&mut self, network: &mut Network, synapse: SynapseId, time: Time) {
+        let syn = &mut network.synapses[synapse.index()];
+
+        let o1 = state_mut(&mut self.post_traces, syn.post_neuron)[0].at(time, self.tau_minus);
+        let [r1, r2] = state_mut(&mut self.pre_traces, synapse);
+        syn.eff_d -= o1 * (self.a2_minus + self.a3_minus * r2.at(time, self.tau_x));
+
+        r1.add(time, self.tau_plus, 1.0);
+        r2.add(time, self.tau_x, 1.0);
+    }
+
+    fn post_spike(&mut self, network: &mut Network, rule: usize, neuron: NeuronId, time: Time) {
+        let [o1, o2] = state_mut(&mut self.post_traces, neuron);
+        let o2_before = o2.at(time, self.tau_y);
+        o1.add(time, self.tau_minus, 1.0);
+        o2.add(time, self.tau_y, 1.0);
+
+        for &synapse_id in network.neurons[neuron.index()].pre_synapses.iter() {
+            if !network.is_plastic(synapse_id, rule) {
+                continue;
+            }
+            let synapse = &mut network.synapses[synapse_id.index()];
+            let r1 = state_mut(&mut self.pre_traces, synapse_id)[0].at(time, self.tau_plus);
+            synapse.eff_d += r1 * (self.a2_plus + self.a3_plus * o2_before);
+        }
+    }
+
+    fn update_weights(&mut self, network: &mut Network, rule: usize, _time: Time) {
+        network.update_rule_weights(rule, self.min_weight, self.max_weight, 0.0);
+    }
+}
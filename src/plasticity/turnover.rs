@@ -0,0 +1,32 @@
+use crate::simulation::Time;
+use crate::Num;
+
+/// The synapses created and pruned by one update of `StructuralPlasticity`.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct Turnover {
+    /// Time of the update (in ms).
+    pub time: Time,
+
+    /// Number of synapses grown.
+    pub created: usize,
+
+    /// Number of synapses pruned.
+    pub pruned: usize,
+
+    /// Number of synapses of the network after the update.
+    pub synapses: usize,
+}
+
+impl Turnover {
+    /// The fraction of synapses replaced, `(created + pruned) / 2` relative
+    /// to the mean number of synapses before and after the update.
+    pub fn rate(&self) -> Num {
+        let before = self.synapses + self.pruned - self.created;
+        let mean = (before + self.synapses) as Num / 2.0;
+        if mean == 0.0 {
+            0.0
+        } else {
+            (self.created + self.pruned) as Num / 2.0 / mean
+        }
+    }
+}
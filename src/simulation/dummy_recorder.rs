@@ -1,9 +1,9 @@
 use crate::network::NeuronId;
-use crate::simulation::{EventRecorder, Timestep};
+use crate::simulation::{EventRecorder, Time};
 
 #[derive(Debug)]
 pub struct DummyRecorder;
 
 impl EventRecorder for DummyRecorder {
-    fn record_fire(&mut self, _neuron_id: NeuronId, _time_step: Timestep) {}
+    fn record_fire(&mut self, _neuron_id: NeuronId, _time: Time) {}
 }
@@ -19,7 +19,7 @@ impl Eq for Event {}
 
 impl PartialOrd for Event {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.at.partial_cmp(&other.at).map(|o| o.reverse())
+        Some(self.cmp(other))
     }
 }
 
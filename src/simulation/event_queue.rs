@@ -5,6 +5,12 @@ pub struct EventQueue {
     heap: BinaryHeap<Event>,
 }
 
+impl Default for EventQueue {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl EventQueue {
     pub fn new() -> Self {
         EventQueue {
@@ -31,6 +37,6 @@ impl EventQueue {
         }
         let ev = self.heap.pop().unwrap();
         assert!(ev.at == at, "old event found");
-        return Some(ev);
+        Some(ev)
     }
 }
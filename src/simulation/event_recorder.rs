@@ -1,6 +1,7 @@
 use crate::network::NeuronId;
-use crate::simulation::Timestep;
+use crate::simulation::Time;
 
 pub trait EventRecorder {
-    fn record_fire(&mut self, neuron_id: NeuronId, time_step: Timestep);
+    /// Called whenever `neuron_id` fires at simulation time `time` (in ms).
+    fn record_fire(&mut self, neuron_id: NeuronId, time: Time);
 }
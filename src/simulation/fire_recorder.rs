@@ -1,19 +1,50 @@
-use crate::network::NeuronId;
-use crate::simulation::{EventRecorder, Timestep};
+use crate::network::{NeuronId, Population};
+use crate::simulation::{EventRecorder, Time};
+use std::collections::HashMap;
 
 #[derive(Debug)]
 pub struct FireRecorder {
-    pub events: Vec<(NeuronId, Timestep)>,
+    pub events: Vec<(NeuronId, Time)>,
+}
+
+impl Default for FireRecorder {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl FireRecorder {
     pub fn new() -> FireRecorder {
         FireRecorder { events: Vec::new() }
     }
+
+    /// The times at which `neuron_id` fired.
+    pub fn spike_times(&self, neuron_id: NeuronId) -> Vec<Time> {
+        self.events
+            .iter()
+            .filter(|&&(id, _)| id == neuron_id)
+            .map(|&(_, time)| time)
+            .collect()
+    }
+
+    /// The firings of the neurons of `population`, as the index of the
+    /// neuron within the population and the time.
+    pub fn population_events(&self, population: &Population) -> Vec<(usize, Time)> {
+        let index_of: HashMap<NeuronId, usize> = population
+            .neurons()
+            .iter()
+            .enumerate()
+            .map(|(i, &neuron_id)| (neuron_id, i))
+            .collect();
+        self.events
+            .iter()
+            .filter_map(|&(id, time)| index_of.get(&id).map(|&i| (i, time)))
+            .collect()
+    }
 }
 
 impl EventRecorder for FireRecorder {
-    fn record_fire(&mut self, neuron_id: NeuronId, time_step: Timestep) {
-        self.events.push((neuron_id, time_step));
+    fn record_fire(&mut self, neuron_id: NeuronId, time: Time) {
+        self.events.push((neuron_id, time));
     }
 }
@@ -3,13 +3,21 @@ pub mod event;
 pub mod event_queue;
 pub mod event_recorder;
 pub mod fire_recorder;
+pub mod noise;
+pub mod noise_source;
 pub mod simulator;
 
 pub type Timestep = usize;
 
+/// Simulation time (in ms). We always use double precision here, so that
+/// long simulations with small time steps keep their resolution.
+pub type Time = f64;
+
 pub use dummy_recorder::DummyRecorder;
 pub use event::Event;
 pub use event_queue::EventQueue;
 pub use event_recorder::EventRecorder;
 pub use fire_recorder::FireRecorder;
+pub use noise::Noise;
+pub use noise_source::NoiseSource;
 pub use simulator::Simulator;
@@ -0,0 +1,22 @@
+use crate::Num;
+
+/// Random input to neurons, which the `Simulator` applies in every time step.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Noise {
+    /// Gaussian white noise current, drawn independently in every time step.
+    ///
+    /// `std` is the standard deviation for a time step of 1 ms, as in the
+    /// random thalamic input of Izhikevich (2003). For other time steps it is
+    /// scaled by `1 / sqrt(dt)`, so that the effect on the neurons does not
+    /// depend on the time step.
+    GaussianWhite { mean: Num, std: Num },
+
+    /// Ornstein-Uhlenbeck process current, which relaxes to `mean` with time
+    /// constant `tau` (in ms) and has the stationary standard deviation `std`.
+    OrnsteinUhlenbeck { mean: Num, std: Num, tau: Num },
+
+    /// Independent Poisson spike trains with `rate` (in Hz) for each neuron.
+    /// Each spike delivers a current pulse of `weight`, like a synapse with
+    /// `Receptor::Current`.
+    Poisson { rate: Num, weight: Num },
+}
@@ -0,0 +1,95 @@
+use crate::network::{Network, NeuronId};
+use crate::simulation::Noise;
+use crate::Num;
+use rand::distributions::{Distribution, Poisson, StandardNormal};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+/// `Noise` attached to a group of neurons, with its own random number
+/// generator, so that simulations are reproducible.
+#[derive(Debug, Clone)]
+pub struct NoiseSource {
+    noise: Noise,
+    neurons: Vec<NeuronId>,
+    rng: StdRng,
+
+    /// current of the Ornstein-Uhlenbeck process of each neuron
+    ou_currents: Vec<Num>,
+}
+
+impl NoiseSource {
+    pub fn new(noise: Noise, neurons: &[NeuronId], seed: u64) -> Self {
+        match noise {
+            Noise::GaussianWhite { std, .. } => assert!(std >= 0.0),
+            Noise::OrnsteinUhlenbeck { std, tau, .. } => assert!(std >= 0.0 && tau > 0.0),
+            Noise::Poisson { rate, .. } => assert!(rate >= 0.0),
+        }
+        NoiseSource {
+            noise,
+            neurons: neurons.to_vec(),
+            rng: StdRng::seed_from_u64(seed),
+            ou_currents: Vec::new(),
+        }
+    }
+
+    pub fn noise(&self) -> Noise {
+        self.noise
+    }
+
+    pub fn neurons(&self) -> &[NeuronId] {
+        &self.neurons
+    }
+
+    #[inline(always)]
+    fn normal(&mut self) -> Num {
+        StandardNormal.sample(&mut self.rng) as Num
+    }
+
+    /// Applies the noise of a time step of `dt` ms to the neurons.
+    pub(crate) fn apply(&mut self, network: &mut Network, dt: Num) {
+        match self.noise {
+            Noise::GaussianWhite { mean, std } => {
+                let std = std / dt.sqrt();
+                for i in 0..self.neurons.len() {
+                    let current = mean + std * self.normal();
+                    if let Some(neuron) = network.live_neuron_mut(self.neurons[i]) {
+                        neuron.i_noise += current;
+                    }
+                }
+            }
+            Noise::OrnsteinUhlenbeck { mean, std, tau } => {
+                if self.ou_currents.is_empty() {
+                    // start from the stationary distribution
+                    self.ou_currents = (0..self.neurons.len())
+                        .map(|_| mean + std * StandardNormal.sample(&mut self.rng) as Num)
+                        .collect();
+                }
+                let decay = (-dt / tau).exp();
+                let diffusion = std * (1.0 - decay * decay).sqrt();
+                for i in 0..self.neurons.len() {
+                    let current =
+                        mean + (self.ou_currents[i] - mean) * decay + diffusion * self.normal();
+                    self.ou_currents[i] = current;
+                    if let Some(neuron) = network.live_neuron_mut(self.neurons[i]) {
+                        neuron.i_noise += current;
+                    }
+                }
+            }
+            Noise::Poisson { rate, weight } => {
+                if rate == 0.0 {
+                    return;
+                }
+                // a no-op cast with the `f64` feature
+                #[allow(clippy::unnecessary_cast)]
+                let mean = (rate * dt / 1000.0) as f64;
+                let poisson = Poisson::new(mean);
+                for &neuron_id in self.neurons.iter() {
+                    let spikes = poisson.sample(&mut self.rng);
+                    if let Some(neuron) = network.live_neuron_mut(neuron_id) {
+                        neuron.input[0].i_inp += weight * spikes as Num;
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,10 +1,15 @@
-use crate::model::StdpConfig;
+use crate::model::{Integrator, StdpConfig};
 use crate::network::{Network, NeuronId, SynapseDelay, SynapseId};
-use crate::simulation::{EventRecorder, Timestep};
+use crate::plasticity::{Homeostasis, PlasticityRule, SpnetStdp, StructuralPlasticity};
+use crate::simulation::{EventRecorder, Noise, NoiseSource, Time, Timestep};
+use crate::Num;
 
 pub struct Simulator {
     current_time_step: Timestep,
 
+    /// Duration of a time step (in ms).
+    dt: Num,
+
     // We use a cyclic buffer with (time_step % max_delay) as index into the futures_spike array
     future_spikes: Vec<Vec<SynapseId>>,
 
@@ -13,20 +18,45 @@ pub struct Simulator {
     /// operator.
     max_delay_bitwise_and_mask: usize,
 
-    /// Spike Time Dependent Plasticity configuration
-    stdp_config: StdpConfig,
+    /// Rules for long-term synaptic plasticity. A synapse is changed by the
+    /// rule of its `SynapseConfig::plasticity_rule`.
+    plasticity: Vec<Box<dyn PlasticityRule>>,
+
+    /// Homeostatic regulation, applied every `homeostasis_interval` time steps.
+    homeostasis: Option<Homeostasis>,
+    homeostasis_interval: Timestep,
+
+    /// Creation and pruning of synapses, applied every
+    /// `structural_plasticity_interval` time steps.
+    structural_plasticity: Option<StructuralPlasticity>,
+    structural_plasticity_interval: Timestep,
+
+    /// Random input, applied in every time step.
+    noise_sources: Vec<NoiseSource>,
+
+    /// Numerical integration method used to update the neurons' state.
+    integrator: Integrator,
 }
 
 impl Simulator {
     /// Creates a new Simulator.
     ///
-    /// `max_delay`: The maximum delay a synapse can have. We use this value to size
-    /// our `future_spikes` array.
+    /// `max_delay`: The expected maximum delay of a synapse. We use this value to size
+    /// our `future_spikes` array, which grows when a spike travels along a longer
+    /// delay.
     ///
-    /// `stdp_config`: STDP configuration
+    /// `stdp_config`: STDP configuration of the default `SpnetStdp` rule.
     ///
     pub fn new(max_delay: SynapseDelay, stdp_config: StdpConfig) -> Simulator {
-        let max_delay = max_delay.get() as usize;
+        Simulator::with_time_step(1.0, max_delay, stdp_config)
+    }
+
+    /// Creates a new Simulator, which advances the network by `dt` ms per step.
+    ///
+    /// See `new` for the remaining arguments.
+    pub fn with_time_step(dt: Num, max_delay: SynapseDelay, stdp_config: StdpConfig) -> Simulator {
+        assert!(dt > 0.0);
+        let max_delay = max_delay.in_steps(dt);
         assert!(max_delay > 1);
         let next_power_of_two = max_delay.checked_next_power_of_two().unwrap();
         assert!(next_power_of_two >= max_delay);
@@ -34,26 +64,131 @@ impl Simulator {
 
         Simulator {
             current_time_step: 0,
+            dt,
             future_spikes: (0..next_power_of_two).map(|_| Vec::new()).collect(),
             max_delay_bitwise_and_mask,
-            stdp_config,
+            plasticity: vec![Box::new(SpnetStdp::from(stdp_config))],
+            homeostasis: None,
+            homeostasis_interval: 1,
+            structural_plasticity: None,
+            structural_plasticity_interval: 1,
+            noise_sources: Vec::new(),
+            integrator: Integrator::default(),
+        }
+    }
+
+    /// Selects the numerical integration method used to update the neurons' state.
+    pub fn set_integrator(&mut self, integrator: Integrator) {
+        self.integrator = integrator;
+    }
+
+    pub fn integrator(&self) -> Integrator {
+        self.integrator
+    }
+
+    /// Replaces the rule for long-term synaptic plasticity, which changes the
+    /// synapses of plasticity rule 0 (the default).
+    pub fn set_plasticity_rule(&mut self, rule: impl PlasticityRule + 'static) {
+        self.plasticity[0] = Box::new(rule);
+    }
+
+    /// Adds another rule for long-term synaptic plasticity, and returns its
+    /// index for `SynapseConfig::with_plasticity_rule`.
+    pub fn add_plasticity_rule(&mut self, rule: impl PlasticityRule + 'static) -> usize {
+        self.plasticity.push(Box::new(rule));
+        self.plasticity.len() - 1
+    }
+
+    /// Applies `homeostasis` every `interval` ms. Panics if it cannot
+    /// regulate a neuron of `network` (see `Homeostasis::check`).
+    pub fn set_homeostasis(&mut self, network: &Network, homeostasis: Homeostasis, interval: Num) {
+        assert!(interval > 0.0);
+        homeostasis.check(network);
+        self.homeostasis = Some(homeostasis);
+        self.homeostasis_interval = ((interval / self.dt).round() as Timestep).max(1);
+    }
+
+    pub fn homeostasis(&self) -> Option<&Homeostasis> {
+        self.homeostasis.as_ref()
+    }
+
+    /// Applies `structural_plasticity` every `interval` ms.
+    pub fn set_structural_plasticity(
+        &mut self,
+        structural_plasticity: StructuralPlasticity,
+        interval: Num,
+    ) {
+        assert!(interval > 0.0);
+        self.structural_plasticity = Some(structural_plasticity);
+        self.structural_plasticity_interval = ((interval / self.dt).round() as Timestep).max(1);
+    }
+
+    pub fn structural_plasticity(&self) -> Option<&StructuralPlasticity> {
+        self.structural_plasticity.as_ref()
+    }
+
+    /// Drives `neurons` with `noise`, using a random number generator seeded
+    /// with `seed`.
+    pub fn add_noise(&mut self, noise: Noise, neurons: &[NeuronId], seed: u64) {
+        self.noise_sources
+            .push(NoiseSource::new(noise, neurons, seed));
+    }
+
+    pub fn noise_sources(&self) -> &[NoiseSource] {
+        &self.noise_sources
+    }
+
+    /// Applies the weight changes accumulated by the plasticity rules.
+    pub fn update_weights(&mut self, network: &mut Network) {
+        let time = self.current_time();
+        for (i, rule) in self.plasticity.iter_mut().enumerate() {
+            rule.update_weights(network, i, time);
         }
     }
 
     #[inline(always)]
     fn timeslot(&self, at: Timestep) -> usize {
-        (at as usize) & self.max_delay_bitwise_and_mask
+        at & self.max_delay_bitwise_and_mask
     }
 
-    #[inline(always)]
-    fn timeslot_in_future(&self, at: Timestep, synapse_delay: SynapseDelay) -> usize {
-        self.timeslot(at + synapse_delay.get() as usize)
+    /// Grows `future_spikes` to hold spikes arriving `delay` steps after the
+    /// current one, keeping the spikes in flight.
+    fn reserve_delay(&mut self, delay: Timestep) {
+        let len = self.future_spikes.len();
+        if delay <= len {
+            return;
+        }
+        let new_len = delay.checked_next_power_of_two().unwrap();
+        let mut future_spikes: Vec<Vec<SynapseId>> = (0..new_len).map(|_| Vec::new()).collect();
+        // the slot of the current step has been processed and holds the
+        // spikes arriving `len` steps later
+        for at in self.current_time_step + 1..=self.current_time_step + len {
+            let idx = self.timeslot(at);
+            future_spikes[at & (new_len - 1)] = std::mem::take(&mut self.future_spikes[idx]);
+        }
+        self.future_spikes = future_spikes;
+        self.max_delay_bitwise_and_mask = new_len - 1;
     }
 
     pub fn current_time_step(&self) -> Timestep {
         self.current_time_step
     }
 
+    /// Duration of a time step (in ms).
+    pub fn dt(&self) -> Num {
+        self.dt
+    }
+
+    /// The current simulation time (in ms).
+    pub fn current_time(&self) -> Time {
+        self.time_of(self.current_time_step)
+    }
+
+    #[inline(always)]
+    fn time_of(&self, time_step: Timestep) -> Time {
+        time_step as Time * self.dt as Time
+    }
+
     /// External input currents have to be set manually by calling `set_external_input`.
     pub fn step(&mut self, network: &mut Network, event_recorder: &mut impl EventRecorder) {
         let time_step = self.current_time_step;
@@ -61,32 +196,84 @@ impl Simulator {
         // Clear all input currents
         network.reset_all_input_currents();
 
+        for noise_source in self.noise_sources.iter_mut() {
+            noise_source.apply(network, self.dt);
+        }
+
         // get all synapse input
         {
             let idx = self.timeslot(time_step);
+            let time = self.time_of(time_step);
             let spikes = &mut self.future_spikes[idx];
 
-            network.process_firing_synapses(spikes);
+            // drop spikes of synapses removed while they were travelling
+            spikes.retain(|&syn_id| network.contains_synapse(syn_id));
+            network.process_firing_synapses(spikes, time);
+            for &syn_id in spikes.iter() {
+                if let Some(i) = network.synapses[syn_id.index()].plasticity_rule {
+                    self.plasticity[i].pre_spike(network, syn_id, time);
+                }
+            }
             spikes.clear();
         }
 
+        // electrical coupling, from the potentials before the update
+        network.apply_gap_junctions();
+
         for i in 0..network.neurons.len() {
-            let activity = network.neurons[i].update_state(self.stdp_config);
+            if network.neurons[i].removed {
+                continue;
+            }
+            let activity = network.neurons[i].update_state(self.integrator, self.dt);
 
             if activity.fires() {
-                let neuron_id = NeuronId::from(i);
-                event_recorder.record_fire(neuron_id, time_step);
+                let neuron_id = NeuronId::new(i, network.neurons[i].generation);
+                let time = self.time_of(time_step);
+                event_recorder.record_fire(neuron_id, time);
 
                 for &syn_id in network.neurons[i].post_synapses.iter() {
-                    let synapse_delay = network.synapses[syn_id.index()].synapse_delay;
-                    let idx = self.timeslot_in_future(time_step, synapse_delay);
+                    let delay = network.synapses[syn_id.index()]
+                        .synapse_delay
+                        .in_steps(self.dt);
+                    self.reserve_delay(delay);
+                    let idx = self.timeslot(time_step + delay);
                     self.future_spikes[idx].push(syn_id);
                 }
 
-                network.excite_all_pre_synapses_of_neuron(neuron_id);
+                for (i, rule) in self.plasticity.iter_mut().enumerate() {
+                    rule.post_spike(network, i, neuron_id, time);
+                }
+
+                if let Some(ref mut homeostasis) = self.homeostasis {
+                    homeostasis.record_spike(neuron_id, time);
+                }
+
+                if let Some(ref mut structural_plasticity) = self.structural_plasticity {
+                    structural_plasticity.record_spike(neuron_id, time);
+                }
             }
         }
 
+        let time = self.time_of(time_step);
+        for (i, rule) in self.plasticity.iter_mut().enumerate() {
+            rule.step(network, i, time, self.dt);
+        }
+        network.decay_dopamine(self.dt);
+
         self.current_time_step += 1;
+
+        if self.current_time_step % self.homeostasis_interval == 0 {
+            let time = self.current_time();
+            if let Some(ref mut homeostasis) = self.homeostasis {
+                homeostasis.update(network, time);
+            }
+        }
+
+        if self.current_time_step % self.structural_plasticity_interval == 0 {
+            let time = self.current_time();
+            if let Some(ref mut structural_plasticity) = self.structural_plasticity {
+                let _ = structural_plasticity.update(network, time);
+            }
+        }
     }
 }
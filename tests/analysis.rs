@@ -0,0 +1,125 @@
+// Checks the single-cell analyses against the known dynamics of the
+// Izhikevich model.
+
+use izhikevich_neurons::analysis::{
+    fi_curve, phase_response_curve, rheobase, saddle_node_current, PhasePlane, Stability,
+    StepResponse,
+};
+use izhikevich_neurons::model::{Integrator, NeuronConfig};
+use izhikevich_neurons::Num;
+
+fn assert_close(expected: Num, actual: Num, tolerance: Num) {
+    assert!(
+        (expected - actual).abs() <= tolerance,
+        "expected {}, got {}",
+        expected,
+        actual
+    );
+}
+
+#[test]
+fn test_phase_plane_of_regular_spiking_neuron() {
+    let config = NeuronConfig::regular_spiking();
+    let phase_plane = PhasePlane::new(&config, 0.0);
+
+    // 0.04 * v^2 + 4.8 * v + 140 = 0 has the roots -70 and -50
+    let fixed_points = phase_plane.fixed_points();
+    assert_eq!(2, fixed_points.len());
+    assert_close(-70.0, fixed_points[0].v, 1.0e-3);
+    assert_close(-14.0, fixed_points[0].u, 1.0e-3);
+    assert_eq!(Stability::StableNode, fixed_points[0].stability);
+    assert_close(-50.0, fixed_points[1].v, 1.0e-3);
+    assert_eq!(Stability::Saddle, fixed_points[1].stability);
+    for fixed_point in fixed_points.iter() {
+        let vector = phase_plane.vector_at(fixed_point.v, fixed_point.u);
+        assert_close(0.0, vector.dv, 1.0e-3);
+        assert_close(0.0, vector.du, 1.0e-3);
+    }
+
+    // below the v-nullcline the potential rises, above it falls
+    assert!(phase_plane.vector_at(-60.0, -20.0).dv > 0.0);
+    assert!(phase_plane.vector_at(-60.0, 0.0).dv < 0.0);
+
+    // a neuron at rest stays there
+    let rest = phase_plane.resting_state().unwrap().state();
+    let trajectory = phase_plane.trajectory(rest, Integrator::default(), 0.1, 100.0);
+    assert_eq!(1001, trajectory.len());
+    assert_close(-70.0, trajectory[1000].1.potential(), 1.0e-3);
+
+    // the fixed points merge at 4 pA and vanish above
+    assert_close(4.0, saddle_node_current(&config).unwrap(), 1.0e-3);
+    assert!(PhasePlane::new(&config, 4.5).fixed_points().is_empty());
+}
+
+#[test]
+fn test_resonator_has_damped_oscillations() {
+    let config = NeuronConfig::resonator();
+    let rest = PhasePlane::new(&config, 0.0).resting_state().unwrap();
+    assert_eq!(Stability::StableFocus, rest.stability);
+    assert!(rest.eigenvalues[0].im != 0.0);
+}
+
+#[test]
+fn test_rheobase() {
+    let config = NeuronConfig::regular_spiking();
+    let rheobase = rheobase(&config, 20.0, 0.01).unwrap();
+
+    // as `u` follows a current step slowly, the neuron fires below the
+    // saddle-node current, but not below the current at which `dv/dt > 0`
+    // for `u` frozen at rest, i.e. 0.04 * v^2 + 5 * v + 154 + I > 0.
+    assert!(rheobase > 2.25 && rheobase < 4.0, "{}", rheobase);
+    assert!(izhikevich_neurons::analysis::rheobase(&config, 2.25, 0.01).is_none());
+}
+
+#[test]
+fn test_fi_curve() {
+    let curve = fi_curve(&NeuronConfig::regular_spiking(), (5.0, 20.0), 4, 1000.0);
+    assert_eq!(4, curve.len());
+    assert_close(20.0, curve[3].current, 1.0e-6);
+
+    // stronger currents fire faster and earlier
+    assert!(curve[0].steady_state_rate() > 0.0);
+    assert!(curve
+        .windows(2)
+        .all(|w| w[1].steady_state_rate() > w[0].steady_state_rate()));
+    assert!(curve
+        .windows(2)
+        .all(|w| w[1].first_spike_latency() < w[0].first_spike_latency()));
+
+    // regular spiking neurons adapt, fast spiking ones do not
+    let rs = StepResponse::measure(&NeuronConfig::regular_spiking(), 10.0, 1000.0);
+    let fs = StepResponse::measure(&NeuronConfig::fast_spiking(), 10.0, 1000.0);
+    assert!(rs.adaptation_index().unwrap() > 0.01);
+    assert!(fs.adaptation_index().unwrap().abs() < 0.01);
+    assert!(
+        StepResponse::measure(&NeuronConfig::regular_spiking(), 0.0, 1000.0)
+            .adaptation_index()
+            .is_none()
+    );
+}
+
+#[test]
+fn test_phase_response_curve() {
+    let config = NeuronConfig::regular_spiking();
+    let curve = phase_response_curve(&config, 10.0, 5.0, 1.0, 10).unwrap();
+    assert_eq!(10, curve.len());
+    assert_close(0.5, curve[5].0, 1.0e-6);
+
+    // excitatory pulses hardly shift the next spike early in the cycle,
+    // and advance it late in the cycle
+    let shifts: Vec<Num> = curve.iter().map(|&(_, shift)| shift.unwrap()).collect();
+    assert!(shifts[..5].iter().all(|shift| shift.abs() < 0.01));
+    assert!(shifts[7] > 0.05);
+    let strongest = (0..10)
+        .max_by(|&a, &b| shifts[a].partial_cmp(&shifts[b]).unwrap())
+        .unwrap();
+    assert!(curve[strongest].0 > 0.5);
+
+    // a pulse early in the cycle switches a bistable neuron to rest
+    let bistable = NeuronConfig::bistability();
+    let curve = phase_response_curve(&bistable, 0.24, 1.0, 5.0, 10).unwrap();
+    assert_eq!(None, curve[2].1);
+    assert!(curve[3..].iter().all(|&(_, shift)| shift.is_some()));
+
+    assert!(phase_response_curve(&config, 0.0, 5.0, 1.0, 10).is_none());
+}
@@ -0,0 +1,335 @@
+// Checks that every neuron type reproduces its reference firing pattern
+// when driven by its reference stimulus protocol.
+
+use izhikevich_neurons::model::{NeuronConfig, NeuronType};
+use izhikevich_neurons::Num;
+
+fn spikes(neuron_type: NeuronType) -> Vec<Num> {
+    let protocol = neuron_type.protocol().unwrap();
+    protocol.spike_times(&NeuronConfig::from(neuron_type))
+}
+
+fn potentials(neuron_type: NeuronType) -> Vec<(Num, Num)> {
+    let protocol = neuron_type.protocol().unwrap();
+    let mut trace = Vec::new();
+    protocol.run(&NeuronConfig::from(neuron_type), |t, state, _| {
+        trace.push((t, state.potential()))
+    });
+    trace
+}
+
+fn intervals(spikes: &[Num]) -> Vec<Num> {
+    spikes.windows(2).map(|w| w[1] - w[0]).collect()
+}
+
+/// Groups spikes which are less than `max_isi` ms apart.
+fn bursts(spikes: &[Num], max_isi: Num) -> Vec<Vec<Num>> {
+    let mut bursts: Vec<Vec<Num>> = Vec::new();
+    for &t in spikes {
+        match bursts.last_mut() {
+            Some(burst) if t - burst[burst.len() - 1] < max_isi => burst.push(t),
+            _ => bursts.push(vec![t]),
+        }
+    }
+    bursts
+}
+
+fn local_extrema(values: &[Num]) -> usize {
+    values
+        .windows(3)
+        .filter(|w| (w[1] - w[0]) * (w[2] - w[1]) < 0.0)
+        .count()
+}
+
+#[test]
+fn test_regular_spiking() {
+    let spikes = spikes(NeuronType::RegularSpiking);
+    let isi = intervals(&spikes);
+    assert!(spikes.len() >= 4);
+    assert!(bursts(&spikes, 10.0).iter().all(|b| b.len() == 1));
+    assert!(isi[0] < isi[isi.len() - 1]);
+}
+
+#[test]
+fn test_intrinsically_bursting() {
+    let bursts = bursts(&spikes(NeuronType::IntrinsicallyBursting), 10.0);
+    assert!(bursts[0].len() >= 2);
+    assert!(bursts.len() >= 4);
+    assert!(bursts[1..].iter().all(|b| b.len() == 1));
+}
+
+#[test]
+fn test_chattering() {
+    let bursts = bursts(&spikes(NeuronType::Chattering), 10.0);
+    assert!(bursts.len() >= 3);
+    assert!(bursts[..3].iter().all(|b| b.len() >= 3));
+}
+
+#[test]
+fn test_fast_spiking() {
+    let spikes = spikes(NeuronType::FastSpiking);
+    assert!(spikes.len() >= 15);
+    assert!(intervals(&spikes).iter().all(|&isi| isi < 12.0));
+}
+
+#[test]
+fn test_low_threshold_spiking() {
+    let spikes = spikes(NeuronType::LowThresholdSpiking);
+    let isi = intervals(&spikes);
+    assert!(spikes.len() >= 10);
+    assert!(2.0 * isi[0] < isi[isi.len() - 1]);
+}
+
+#[test]
+fn test_thalamo_cortical() {
+    let spikes = spikes(NeuronType::ThalamoCortical);
+    assert!(spikes.len() >= 2);
+    assert!(spikes.iter().all(|&t| t > 100.0));
+    assert_eq!(1, bursts(&spikes, 15.0).len());
+}
+
+#[test]
+fn test_tonic_spiking() {
+    let spikes = spikes(NeuronType::TonicSpiking);
+    let isi = intervals(&spikes);
+    let n = isi.len();
+    assert!(spikes.len() >= 4);
+    assert!((isi[n - 1] - isi[n - 2]).abs() < 1.0);
+}
+
+#[test]
+fn test_phasic_spiking() {
+    let spikes = spikes(NeuronType::PhasicSpiking);
+    assert_eq!(1, spikes.len());
+    assert!(spikes[0] > 20.0);
+}
+
+#[test]
+fn test_tonic_bursting() {
+    let bursts = bursts(&spikes(NeuronType::TonicBursting), 10.0);
+    assert!(bursts.len() >= 3);
+    assert!(bursts.iter().all(|b| b.len() >= 3));
+}
+
+#[test]
+fn test_phasic_bursting() {
+    let spikes = spikes(NeuronType::PhasicBursting);
+    assert!(spikes.len() >= 3);
+    assert_eq!(1, bursts(&spikes, 10.0).len());
+    assert!(spikes[spikes.len() - 1] < 100.0);
+}
+
+#[test]
+fn test_mixed_mode() {
+    let spikes = spikes(NeuronType::MixedMode);
+    let bursts = bursts(&spikes, 10.0);
+    assert!(bursts[0].len() >= 2);
+    assert!(bursts.len() >= 3);
+    assert!(bursts[1..].iter().all(|b| b.len() == 1));
+}
+
+#[test]
+fn test_spike_frequency_adaptation() {
+    let isi = intervals(&spikes(NeuronType::SpikeFrequencyAdaptation));
+    assert!(isi.len() >= 4);
+    assert!(isi.windows(2).all(|w| w[0] < w[1]));
+}
+
+#[test]
+fn test_class_1_excitable() {
+    let isi = intervals(&spikes(NeuronType::Class1Excitable));
+    assert!(isi.len() >= 5);
+    assert!(isi[0] > 35.0);
+    assert!(isi.windows(2).all(|w| w[0] > w[1]));
+}
+
+#[test]
+fn test_class_2_excitable() {
+    let isi = intervals(&spikes(NeuronType::Class2Excitable));
+    assert!(isi.len() >= 5);
+    assert!(isi[0] < 25.0);
+    assert!(isi.windows(2).all(|w| w[0] >= w[1] - 1.0));
+}
+
+#[test]
+fn test_spike_latency() {
+    let spikes = spikes(NeuronType::SpikeLatency);
+    assert_eq!(1, spikes.len());
+    // the stimulus ends at 13 ms
+    assert!(spikes[0] > 23.0);
+}
+
+#[test]
+fn test_subthreshold_oscillations() {
+    let spikes = spikes(NeuronType::SubthresholdOscillations);
+    assert_eq!(1, spikes.len());
+    let after_spike: Vec<Num> = potentials(NeuronType::SubthresholdOscillations)
+        .into_iter()
+        .filter(|&(t, _)| t > spikes[0])
+        .map(|(_, v)| v)
+        .collect();
+    assert!(local_extrema(&after_spike) >= 3);
+}
+
+#[test]
+fn test_resonator() {
+    let spikes = spikes(NeuronType::Resonator);
+    assert_eq!(1, spikes.len());
+    // only the last pair of pulses has the resonant frequency
+    assert!(spikes[0] > 320.0);
+}
+
+#[test]
+fn test_integrator() {
+    let spikes = spikes(NeuronType::Integrator);
+    assert_eq!(1, spikes.len());
+    // only the first pair of pulses is close enough to be integrated
+    assert!(spikes[0] < 70.0);
+}
+
+#[test]
+fn test_rebound_spike() {
+    let spikes = spikes(NeuronType::ReboundSpike);
+    assert_eq!(1, spikes.len());
+    assert!(spikes[0] > 25.0);
+}
+
+#[test]
+fn test_rebound_burst() {
+    let spikes = spikes(NeuronType::ReboundBurst);
+    assert!(spikes.len() >= 3);
+    assert!(spikes[0] > 25.0);
+    assert_eq!(1, bursts(&spikes, 10.0).len());
+}
+
+#[test]
+fn test_threshold_variability() {
+    let spikes = spikes(NeuronType::ThresholdVariability);
+    assert_eq!(1, spikes.len());
+    // the same pulse only fires after a preceding inhibitory pulse
+    assert!(spikes[0] > 80.0);
+}
+
+#[test]
+fn test_bistability() {
+    let spikes = spikes(NeuronType::Bistability);
+    assert!(spikes.len() >= 3);
+    assert!(spikes[0] > 37.5);
+    assert!(spikes[spikes.len() - 1] < 221.0);
+}
+
+#[test]
+fn test_depolarizing_after_potential() {
+    let spikes = spikes(NeuronType::DepolarizingAfterPotential);
+    assert_eq!(1, spikes.len());
+    let after_potential = potentials(NeuronType::DepolarizingAfterPotential)
+        .into_iter()
+        .filter(|&(t, _)| t > spikes[0] + 1.0 && t < spikes[0] + 5.0)
+        .all(|(_, v)| v > -55.0);
+    assert!(after_potential);
+}
+
+#[test]
+fn test_accommodation() {
+    let spikes = spikes(NeuronType::Accommodation);
+    assert_eq!(1, spikes.len());
+    // the slow ramp does not elicit a spike, while the fast one does.
+    assert!(spikes[0] > 300.0);
+}
+
+#[test]
+fn test_inhibition_induced_spiking() {
+    let spikes = spikes(NeuronType::InhibitionInducedSpiking);
+    assert!(spikes.len() >= 2);
+    assert!(spikes.iter().all(|&t| t > 50.0 && t < 260.0));
+    assert!(bursts(&spikes, 10.0).iter().all(|b| b.len() == 1));
+}
+
+#[test]
+fn test_inhibition_induced_bursting() {
+    let spikes = spikes(NeuronType::InhibitionInducedBursting);
+    let bursts = bursts(&spikes, 10.0);
+    assert!(spikes.iter().all(|&t| t > 50.0 && t < 260.0));
+    assert!(bursts.len() >= 2);
+    assert!(bursts.iter().all(|b| b.len() >= 3));
+}
+
+#[test]
+fn test_randomized_types_have_no_protocol() {
+    use izhikevich_neurons::Closed01;
+    assert!(NeuronType::Excitatory(Closed01::new(0.5))
+        .protocol()
+        .is_none());
+    assert!(NeuronType::Inhibitory(Closed01::new(0.5))
+        .protocol()
+        .is_none());
+}
+
+#[test]
+fn test_classify_cortical_types() {
+    use izhikevich_neurons::analysis::{FiringPattern, StepResponse};
+    let classify = |config: NeuronConfig, current: Num| {
+        StepResponse::measure(&config, current, 1000.0).firing_pattern()
+    };
+    assert_eq!(
+        FiringPattern::Adapting,
+        classify(NeuronConfig::regular_spiking(), 10.0)
+    );
+    assert_eq!(
+        FiringPattern::MixedMode,
+        classify(NeuronConfig::intrinsically_bursting(), 10.0)
+    );
+    assert_eq!(
+        FiringPattern::Chattering,
+        classify(NeuronConfig::chattering(), 15.0)
+    );
+    assert_eq!(
+        FiringPattern::FastSpiking,
+        classify(NeuronConfig::fast_spiking(), 10.0)
+    );
+    assert_eq!(
+        FiringPattern::PhasicSpiking,
+        classify(NeuronConfig::phasic_spiking(), 0.5)
+    );
+    assert_eq!(
+        FiringPattern::PhasicBursting,
+        classify(NeuronConfig::phasic_bursting(), 0.6)
+    );
+    assert_eq!(
+        FiringPattern::Silent,
+        classify(NeuronConfig::regular_spiking(), 0.0)
+    );
+}
+
+#[test]
+fn test_burst_statistics() {
+    use izhikevich_neurons::analysis::StepResponse;
+    let stats = StepResponse::measure(&NeuronConfig::chattering(), 10.0, 1000.0).burst_statistics();
+    assert!(stats.bursts.len() > 10);
+    assert_eq!(0, stats.single_spikes);
+    assert!(stats.spikes_per_burst > 3.0);
+    assert!(stats.intra_burst_interval < 5.0);
+    assert!(stats.inter_burst_interval > 5.0 * stats.intra_burst_interval);
+}
+
+#[test]
+fn test_classify_randomized_excitatory() {
+    use izhikevich_neurons::analysis::{FiringPattern, StepResponse};
+    use izhikevich_neurons::Closed01;
+    for i in 0..=20 {
+        let config = NeuronConfig::excitatory(Closed01::new(i as Num / 20.0));
+        let pattern = StepResponse::measure(&config, 10.0, 1000.0).firing_pattern();
+        assert!(
+            matches!(
+                pattern,
+                FiringPattern::Adapting
+                    | FiringPattern::MixedMode
+                    | FiringPattern::Chattering
+                    | FiringPattern::TonicBursting
+            ),
+            "unexpected {:?} for r = {}",
+            pattern,
+            i as Num / 20.0
+        );
+    }
+}
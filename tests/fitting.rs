@@ -0,0 +1,90 @@
+// Checks that neuron parameters can be recovered from recordings.
+
+use izhikevich_neurons::fitting::{fit, gamma_factor, Cost, NelderMead, Recording};
+use izhikevich_neurons::model::NeuronConfig;
+use izhikevich_neurons::Num;
+
+const DT: Num = 0.25;
+
+/// A recording of `config` receiving `current` from 50 ms on, for 500 ms.
+fn recording(config: &NeuronConfig, current: Num) -> Recording {
+    let currents: Vec<Num> = (0..2000)
+        .map(|i| if i as Num * DT >= 50.0 { current } else { 0.0 })
+        .collect();
+    let v0 = -70.0;
+    let spike_times = Recording::from_spike_times(DT, currents.clone(), vec![], v0);
+    let (voltage, _) = spike_times.simulate(config);
+    Recording::from_voltage(DT, currents, voltage, 30.0)
+}
+
+#[test]
+fn test_fit_recovers_parameters() {
+    let target = NeuronConfig::new(0.02, 0.2, -55.0, 4.0);
+    let recording = recording(&target, 10.0);
+    assert!(recording.spike_times.len() > 10);
+    let initial = NeuronConfig::new(0.02, 0.21, -56.0, 4.5);
+
+    // the spike train is reproduced exactly by parameters close to the target
+    let cost = Cost::GammaFactor { delta: 2.0 };
+    assert!(cost.evaluate(&initial, &recording) > 0.4);
+    let result = fit(&recording, cost, &initial, &NelderMead::default());
+    assert!(result.cost < 1.0e-3, "{:?}", result);
+    assert!((result.config.c - target.c).abs() < 1.0);
+    assert!((result.config.d - target.d).abs() < 1.0);
+
+    // the voltage error has many local minima, but is lowered
+    let initial_cost = Cost::VoltageRmse.evaluate(&initial, &recording);
+    let result = fit(
+        &recording,
+        Cost::VoltageRmse,
+        &initial,
+        &NelderMead::default(),
+    );
+    assert!(result.cost < initial_cost, "{:?}", result);
+}
+
+#[test]
+fn test_fit_allows_negative_recovery_rate() {
+    // a = -0.02, as for inhibition-induced spiking
+    let target = NeuronConfig::inhibition_induced_spiking();
+    let recording = recording(&target, 75.0);
+    let initial = NeuronConfig::new(-0.02, -1.0, -55.0, 8.0);
+    let cost = Cost::GammaFactor { delta: 2.0 };
+    let initial_cost = cost.evaluate(&initial, &recording);
+    assert!(initial_cost > 0.5);
+    let result = fit(&recording, cost, &initial, &NelderMead::default());
+    assert!(result.cost < initial_cost, "{:?}", result);
+}
+
+#[test]
+fn test_unsuitable_costs_are_infinite() {
+    let config = NeuronConfig::regular_spiking();
+    let voltage = recording(&config, 10.0);
+    let spikes_only = Recording::from_spike_times(
+        DT,
+        voltage.current.clone(),
+        voltage.spike_times.clone(),
+        -70.0,
+    );
+
+    // there is no voltage to compare with
+    assert_eq!(
+        Num::INFINITY,
+        Cost::VoltageRmse.evaluate(&config, &spikes_only)
+    );
+    let result = fit(
+        &spikes_only,
+        Cost::VoltageRmse,
+        &config,
+        &NelderMead::default(),
+    );
+    assert_eq!(Num::INFINITY, result.cost);
+
+    // coincidences within 100 ms are meaningless at this rate
+    assert!(gamma_factor(&voltage.spike_times, &voltage.spike_times, 100.0, 500.0).is_nan());
+    assert_eq!(
+        Num::INFINITY,
+        Cost::GammaFactor { delta: 100.0 }.evaluate(&config, &voltage)
+    );
+    assert!(Cost::GammaFactor { delta: 2.0 }.evaluate(&config, &voltage) < 1.0e-6);
+}
@@ -0,0 +1,114 @@
+// Checks the numerical integrators against systems with known solutions.
+
+use izhikevich_neurons::model::{Integrator, OdeSystem};
+use izhikevich_neurons::Num;
+
+/// `dy/dt = -y / tau`, with the solution `y(t) = y(0) * exp(-t / tau)`.
+struct Decay {
+    tau: Num,
+}
+
+impl OdeSystem<1> for Decay {
+    fn derivative(&self, y: &[Num; 1]) -> [Num; 1] {
+        [-y[0] / self.tau]
+    }
+
+    fn diagonal_jacobian(&self, _y: &[Num; 1]) -> [Num; 1] {
+        [-1.0 / self.tau]
+    }
+}
+
+/// An oscillator `x'' = -x`, with the solution `x(t) = cos(t)` for
+/// `x(0) = 1, x'(0) = 0`.
+struct Oscillator;
+
+impl OdeSystem<2> for Oscillator {
+    fn derivative(&self, y: &[Num; 2]) -> [Num; 2] {
+        [y[1], -y[0]]
+    }
+
+    fn diagonal_jacobian(&self, _y: &[Num; 2]) -> [Num; 2] {
+        [0.0, 0.0]
+    }
+}
+
+/// A system whose derivative is not defined anywhere.
+struct Diverged;
+
+impl OdeSystem<1> for Diverged {
+    fn derivative(&self, _y: &[Num; 1]) -> [Num; 1] {
+        [Num::NAN]
+    }
+
+    fn diagonal_jacobian(&self, _y: &[Num; 1]) -> [Num; 1] {
+        [0.0]
+    }
+}
+
+fn solve<S: OdeSystem<N>, const N: usize>(
+    integrator: Integrator,
+    system: &S,
+    mut y: [Num; N],
+    steps: usize,
+    dt: Num,
+) -> [Num; N] {
+    for _ in 0..steps {
+        y = integrator.integrate(system, y, dt);
+    }
+    y
+}
+
+#[test]
+fn test_exponential_euler_is_exact_for_linear_decay() {
+    let system = Decay { tau: 2.0 };
+    let integrator = Integrator::ExponentialEuler { substeps: 1 };
+    let y = solve(integrator, &system, [1.0], 10, 1.0);
+    assert!((y[0] - (-5.0 as Num).exp()).abs() < 1.0e-6);
+
+    // forward Euler with the same step is far off
+    let y = solve(
+        Integrator::ForwardEuler { substeps: 1 },
+        &system,
+        [1.0],
+        10,
+        1.0,
+    );
+    assert!((y[0] - (-5.0 as Num).exp()).abs() > 1.0e-3);
+}
+
+#[test]
+fn test_runge_kutta_accuracy() {
+    let exact = (10.0 as Num).cos();
+    let rk4 = solve(
+        Integrator::RungeKutta4 { substeps: 10 },
+        &Oscillator,
+        [1.0, 0.0],
+        10,
+        1.0,
+    );
+    assert!((rk4[0] - exact).abs() < 1.0e-4);
+
+    let rk45 = solve(
+        Integrator::AdaptiveRk45 { tolerance: 1.0e-6 },
+        &Oscillator,
+        [1.0, 0.0],
+        10,
+        1.0,
+    );
+    assert!((rk45[0] - exact).abs() < 1.0e-4);
+
+    let euler = solve(
+        Integrator::ForwardEuler { substeps: 10 },
+        &Oscillator,
+        [1.0, 0.0],
+        10,
+        1.0,
+    );
+    assert!((euler[0] - exact).abs() > 1.0e-2);
+}
+
+#[test]
+#[should_panic(expected = "non-finite state")]
+fn test_adaptive_step_rejects_non_finite_state() {
+    let _ = Integrator::AdaptiveRk45 { tolerance: 1.0e-6 }.integrate(&Diverged, [0.0], 1.0);
+}
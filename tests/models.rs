@@ -0,0 +1,82 @@
+// Checks the behaviour of the neuron models on their own.
+
+use izhikevich_neurons::model::{
+    AdExConfig, HodgkinHuxleyConfig, Integrator, LifConfig, NeuronConfig, NeuronModel,
+    SimpleModelConfig,
+};
+use izhikevich_neurons::Num;
+
+/// The number of spikes of `model` driven by `current` for `duration` ms.
+fn spike_count<M: NeuronModel>(
+    model: &M,
+    current: Num,
+    duration: Num,
+    dt: Num,
+    integrator: Integrator,
+) -> usize {
+    let mut state = model.initial_state();
+    let mut spikes = 0;
+    for _ in 0..(duration / dt).round() as usize {
+        let (new_state, activity) = model.update(state, current, dt, integrator);
+        state = new_state;
+        if activity.fires() {
+            spikes += 1;
+        }
+    }
+    spikes
+}
+
+#[test]
+fn test_models_are_silent_at_rest_and_fire_with_input() {
+    let euler = Integrator::default();
+    let exponential = Integrator::ExponentialEuler { substeps: 1 };
+
+    assert_eq!(
+        0,
+        spike_count(&LifConfig::default(), 5.0, 500.0, 0.1, euler)
+    );
+    assert!(spike_count(&LifConfig::default(), 10.0, 500.0, 0.1, euler) >= 10);
+
+    assert_eq!(
+        0,
+        spike_count(&AdExConfig::default(), 0.0, 500.0, 0.1, euler)
+    );
+    assert!(spike_count(&AdExConfig::default(), 800.0, 500.0, 0.1, euler) >= 5);
+
+    let hh = HodgkinHuxleyConfig::default();
+    assert_eq!(0, spike_count(&hh, 0.0, 200.0, 0.01, exponential));
+    assert!(spike_count(&hh, 10.0, 200.0, 0.01, exponential) >= 5);
+
+    let rs = NeuronConfig::regular_spiking();
+    assert_eq!(0, spike_count(&rs, 0.0, 500.0, 1.0, euler));
+    assert!(spike_count(&rs, 10.0, 500.0, 1.0, euler) >= 5);
+}
+
+#[test]
+fn test_simple_model() {
+    let euler = Integrator::default();
+    let rs = SimpleModelConfig::regular_spiking();
+
+    // at rest, the potential stays at v_r
+    let mut state = rs.initial_state();
+    for _ in 0..1000 {
+        state = rs.update(state, 0.0, 0.1, euler).0;
+    }
+    assert!((rs.potential(&state) - rs.v_r).abs() < 0.5);
+
+    // currents are in pA
+    assert_eq!(0, spike_count(&rs, 20.0, 1000.0, 0.1, euler));
+    let rs_spikes = spike_count(&rs, 100.0, 1000.0, 0.1, euler);
+    assert!(rs_spikes >= 2);
+
+    // fast spiking interneurons fire faster with the same current
+    let fs = SimpleModelConfig::fast_spiking();
+    assert!(
+        spike_count(&fs, 300.0, 1000.0, 0.1, euler) > spike_count(&rs, 300.0, 1000.0, 0.1, euler)
+    );
+
+    // a higher threshold needs more current
+    let mut shifted = rs.clone();
+    shifted.shift_threshold(10.0);
+    assert!(spike_count(&shifted, 100.0, 1000.0, 0.1, euler) < rs_spikes);
+}
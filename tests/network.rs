@@ -0,0 +1,279 @@
+// Checks how networks are built from groups of neurons.
+
+use izhikevich_neurons::model::{NeuronConfig, Receptor};
+use izhikevich_neurons::network::{
+    ConnectionKernel, Connector, DelayDistribution, Layout, Network, NetworkBuilder, NeuronId,
+    NeuronTypeDistribution, SynapseConfig, SynapseDelay, Topology,
+};
+use izhikevich_neurons::Num;
+
+/// The `(pre, post)` indices of the synapses of `network`.
+fn pairs(network: &Network) -> Vec<(usize, usize)> {
+    network
+        .synapse_ids()
+        .map(|synapse| {
+            let synapse = network.synapse_ref(synapse);
+            (synapse.pre_neuron().index(), synapse.post_neuron().index())
+        })
+        .collect()
+}
+
+/// The synapses `topology` generates within a group of `n` neurons, as
+/// `(pre, post)` indices.
+fn connect(topology: Topology, n: usize, seed: u64) -> Vec<(usize, usize)> {
+    let mut builder = NetworkBuilder::new();
+    let neurons: Vec<NeuronId> = (0..n)
+        .map(|_| builder.create_neuron(NeuronConfig::regular_spiking()))
+        .collect();
+    let connector = Connector::new(topology, 1.0).with_seed(seed);
+    builder.connect_topology(&neurons, &neurons, &connector);
+    let mut pairs = pairs(&builder.into_network());
+
+    // no neuron is connected to itself, and no pair twice
+    assert!(pairs.iter().all(|&(pre, post)| pre != post));
+    pairs.sort();
+    let len = pairs.len();
+    pairs.dedup();
+    assert_eq!(len, pairs.len());
+    pairs
+}
+
+fn assert_close(expected: Num, actual: Num) {
+    assert!(
+        (expected - actual).abs() <= 1.0e-3,
+        "expected {}, got {}",
+        expected,
+        actual
+    );
+}
+
+/// The number of synapses from (`out_degree`) or onto each of `n` neurons.
+fn degrees(pairs: &[(usize, usize)], n: usize, out_degree: bool) -> Vec<usize> {
+    let mut degrees = vec![0; n];
+    for &(pre, post) in pairs {
+        degrees[if out_degree { pre } else { post }] += 1;
+    }
+    degrees
+}
+
+#[test]
+fn test_topologies_are_reproducible() {
+    let kernel = ConnectionKernel::Gaussian {
+        p_max: 0.5,
+        sigma: 2.0,
+    };
+    for &topology in &[
+        Topology::FixedInDegree(5),
+        Topology::FixedOutDegree(5),
+        Topology::ErdosRenyi(0.1),
+        Topology::WattsStrogatz { k: 4, beta: 0.2 },
+        Topology::BarabasiAlbert { m: 2 },
+        Topology::Lattice {
+            layout: Layout::Grid {
+                shape: [7, 7, 1],
+                spacing: 1.0,
+            },
+            kernel,
+        },
+    ] {
+        let pairs = connect(topology, 49, 1);
+        assert!(!pairs.is_empty());
+        assert_eq!(pairs, connect(topology, 49, 1), "{:?}", topology);
+        assert_ne!(pairs, connect(topology, 49, 2), "{:?}", topology);
+    }
+}
+
+#[test]
+fn test_topology_degrees() {
+    let n = 50;
+    let in_degrees = degrees(&connect(Topology::FixedInDegree(5), n, 1), n, false);
+    assert!(in_degrees.iter().all(|&k| k == 5));
+    let out_degrees = degrees(&connect(Topology::FixedOutDegree(5), n, 1), n, true);
+    assert!(out_degrees.iter().all(|&k| k == 5));
+
+    // 245 expected synapses, with a standard deviation of about 15
+    let synapses = connect(Topology::ErdosRenyi(0.1), n, 1).len();
+    assert!(synapses > 185 && synapses < 305, "{}", synapses);
+
+    // a ring connects neighbours only, rewiring keeps the out-degree
+    let ring = connect(Topology::WattsStrogatz { k: 4, beta: 0.0 }, n, 1);
+    assert_eq!(4 * n, ring.len());
+    let neighbours =
+        |&(pre, post): &(usize, usize)| (pre + n - post) % n <= 2 || (post + n - pre) % n <= 2;
+    assert!(ring.iter().all(neighbours));
+    let small_world = connect(Topology::WattsStrogatz { k: 4, beta: 0.2 }, n, 1);
+    assert!(degrees(&small_world, n, true).iter().all(|&k| k == 4));
+    assert!(small_world.iter().any(|pair| !neighbours(pair)));
+
+    // every added neuron brings `m` reciprocal edges, and early neurons
+    // become hubs
+    let scale_free = connect(Topology::BarabasiAlbert { m: 2 }, n, 1);
+    assert_eq!(2 * 2 * (n - 2), scale_free.len());
+    let in_degrees = degrees(&scale_free, n, false);
+    assert_eq!(in_degrees, degrees(&scale_free, n, true));
+    assert!(in_degrees.iter().all(|&k| k >= 2));
+    assert!(*in_degrees.iter().max().unwrap() > 3 * 2);
+}
+
+#[test]
+fn test_lattice_topology() {
+    let kernel = ConnectionKernel::Gaussian {
+        p_max: 1.0,
+        sigma: 1.0,
+    };
+    let lattice = |layout| connect(Topology::Lattice { layout, kernel }, 25, 1);
+    let shape = [5, 5, 1];
+    let grid = lattice(Layout::Grid {
+        shape,
+        spacing: 1.0,
+    });
+    let torus = lattice(Layout::Torus {
+        shape,
+        spacing: 1.0,
+    });
+
+    // neurons at opposite borders are 4 mm apart on the grid, but
+    // neighbours on the torus
+    let opposite = |a: usize, b: usize| a.min(b) == 0 && a.max(b) == 4;
+    let across = |pairs: &[(usize, usize)]| {
+        pairs
+            .iter()
+            .filter(|&&(pre, post)| {
+                (pre % 5 == post % 5 && opposite(pre / 5, post / 5))
+                    || (pre / 5 == post / 5 && opposite(pre % 5, post % 5))
+            })
+            .count()
+    };
+    assert_eq!(0, across(&grid));
+    assert!(across(&torus) > 5, "{}", across(&torus));
+
+    // the spacing scales the distances
+    let sparse = lattice(Layout::Grid {
+        shape,
+        spacing: 2.0,
+    });
+    assert!(sparse.len() < grid.len() / 2);
+}
+
+/// A sheet of `shape` neurons with 1 mm spacing, on a grid or a torus.
+fn sheet(shape: [usize; 3], torus: bool) -> (NetworkBuilder, Vec<NeuronId>) {
+    let mut builder = NetworkBuilder::new();
+    let neurons: Vec<NeuronId> = (0..shape.iter().product())
+        .map(|_| builder.create_neuron(NeuronConfig::regular_spiking()))
+        .collect();
+    let layout = if torus {
+        Layout::Torus {
+            shape,
+            spacing: 1.0,
+        }
+    } else {
+        Layout::Grid {
+            shape,
+            spacing: 1.0,
+        }
+    };
+    builder.set_space(layout.space());
+    builder.place(&neurons, layout);
+    (builder, neurons)
+}
+
+#[test]
+fn test_torus_distance() {
+    let (grid, neurons) = sheet([5, 4, 1], false);
+    let (torus, _) = sheet([5, 4, 1], true);
+    let (first, last) = (neurons[0], neurons[19]);
+    assert_eq!(Some(1.0), grid.distance(neurons[0], neurons[1]));
+    assert_eq!(Some(1.0), torus.distance(neurons[0], neurons[1]));
+
+    // opposite corners are diagonal neighbours on the torus
+    assert_close(5.0, grid.distance(first, last).unwrap());
+    assert_close((2.0 as Num).sqrt(), torus.distance(first, last).unwrap());
+    assert_close(2.0, torus.distance(neurons[0], neurons[2 * 5]).unwrap());
+    assert_close(2.0, torus.distance(neurons[0], neurons[3]).unwrap());
+}
+
+#[test]
+#[should_panic(expected = "the layout requires Torus")]
+fn test_torus_requires_its_space() {
+    let mut builder = NetworkBuilder::new();
+    let neurons: Vec<NeuronId> = (0..4)
+        .map(|_| builder.create_neuron(NeuronConfig::regular_spiking()))
+        .collect();
+    builder.place(
+        &neurons,
+        Layout::Torus {
+            shape: [2, 2, 1],
+            spacing: 1.0,
+        },
+    );
+}
+
+#[test]
+fn test_conduction_delays() {
+    // 0.5 m/s takes 2 ms per mm
+    let delays = DelayDistribution::Conduction {
+        velocity: 0.5,
+        synaptic_delay: SynapseDelay::new(1),
+    };
+    for &torus in &[false, true] {
+        let (mut builder, neurons) = sheet([5, 4, 1], torus);
+        let connector = Connector::new(Topology::ErdosRenyi(1.0), 1.0).with_delays(delays);
+        builder.connect_topology(&neurons, &neurons, &connector);
+        let distances: Vec<Num> = neurons
+            .iter()
+            .flat_map(|&pre| neurons.iter().map(move |&post| (pre, post)))
+            .filter(|&(pre, post)| pre != post)
+            .map(|(pre, post)| builder.distance(pre, post).unwrap())
+            .collect();
+        let network = builder.into_network();
+        for (synapse, &distance) in network.synapse_ids().zip(distances.iter()) {
+            let delay = network.synapse_ref(synapse).synapse_delay();
+            assert_close(1.0 + 2.0 * distance, delay.as_ms());
+        }
+
+        // the longest delay is across the sheet
+        let longest = if torus { (8.0 as Num).sqrt() } else { 5.0 };
+        let max_delay = network.max_synapse_delay().unwrap();
+        assert_close(1.0 + 2.0 * longest, max_delay.as_ms());
+    }
+}
+
+#[test]
+fn test_populations_and_projections() {
+    let mut builder = NetworkBuilder::new();
+    let exc = builder.create_population("exc", 8, NeuronTypeDistribution::Excitatory, 1);
+    let inh = builder.create_population("inh", 2, NeuronTypeDistribution::Inhibitory, 1);
+    let all_to_all = Connector::new(Topology::ErdosRenyi(1.0), 1.0);
+    builder.project("exc->inh", exc, inh, &all_to_all);
+    let fixed = all_to_all.with_synapse(SynapseConfig::from(Receptor::GabaA).fixed());
+    builder.project("inh->exc", inh, exc, &fixed);
+    let plastic = all_to_all.with_synapse(SynapseConfig::default().with_plasticity_rule(1));
+    builder.project("exc->exc", exc, exc, &plastic);
+    let network = builder.into_network();
+
+    // populations are found by name, and neurons by population
+    assert_eq!(Some(exc), network.find_population("exc"));
+    assert_eq!(Some(inh), network.find_population("inh"));
+    assert_eq!(None, network.find_population("thalamus"));
+    let population = network.population(inh);
+    assert_eq!("inh", population.name());
+    assert_eq!(2, population.size());
+    let neuron = population.neuron(1);
+    assert_eq!(Some(1), population.index_of(neuron));
+    assert!(!network.population(exc).contains(neuron));
+
+    // each projection assigns its synapses to its plasticity rule
+    for &(name, synapses, rule) in &[
+        ("exc->inh", 16, Some(0)),
+        ("inh->exc", 16, None),
+        ("exc->exc", 56, Some(1)),
+    ] {
+        let projection = network.projection(network.find_projection(name).unwrap());
+        assert_eq!(synapses, projection.synapses().len());
+        assert_eq!(rule, projection.plasticity_rule());
+        for &synapse in projection.synapses() {
+            assert_eq!(rule, network.synapse_ref(synapse).plasticity_rule());
+        }
+    }
+    assert_eq!(None, network.find_projection("inh->inh"));
+}
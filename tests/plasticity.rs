@@ -0,0 +1,419 @@
+// Checks the rules for long-term synaptic plasticity.
+
+use izhikevich_neurons::model::{
+    AdExConfig, HodgkinHuxleyConfig, LifConfig, NeuronConfig, NeuronModel, Receptor, StdpConfig,
+};
+use izhikevich_neurons::network::{
+    Connector, Network, NetworkBuilder, NeuronId, ProjectionId, SynapseConfig, SynapseDelay,
+    SynapseId, Topology,
+};
+use izhikevich_neurons::plasticity::{
+    Excitability, Homeostasis, PairStdp, PlasticityRule, RewardStdp, StructuralPlasticity,
+    TripletStdp,
+};
+use izhikevich_neurons::simulation::{DummyRecorder, FireRecorder, Simulator, Time};
+use izhikevich_neurons::Num;
+use std::panic::AssertUnwindSafe;
+
+/// Two neurons connected by a single synapse.
+fn pair() -> (Network, NeuronId, NeuronId, SynapseId) {
+    let mut builder = NetworkBuilder::new();
+    let pre = builder.create_neuron(NeuronConfig::regular_spiking());
+    let post = builder.create_neuron(NeuronConfig::regular_spiking());
+    let synapse = builder.connect(pre, post, SynapseDelay::new(1), 1.0, Receptor::Current);
+    (builder.into_network(), pre, post, synapse)
+}
+
+/// The `eff_d` accumulated by `rule` for spikes arriving at the synapse at
+/// `pre_spikes` and post-synaptic spikes at `post_spikes` (in ms).
+fn eff_d(mut rule: impl PlasticityRule, pre_spikes: &[Time], post_spikes: &[Time]) -> Num {
+    let (mut network, _, post, synapse) = pair();
+    let mut events: Vec<(Time, bool)> = pre_spikes
+        .iter()
+        .map(|&t| (t, true))
+        .chain(post_spikes.iter().map(|&t| (t, false)))
+        .collect();
+    events.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    for (time, is_pre) in events {
+        if is_pre {
+            rule.pre_spike(&mut network, synapse, time);
+        } else {
+            rule.post_spike(&mut network, 0, post, time);
+        }
+    }
+    network.synapse_ref(synapse).eff_d()
+}
+
+fn assert_close(expected: Num, actual: Num) {
+    assert!(
+        (expected - actual).abs() <= 1.0e-6 * expected.abs().max(1.0e-3),
+        "expected {}, got {}",
+        expected,
+        actual
+    );
+}
+
+#[test]
+fn test_pair_stdp() {
+    let stdp = || PairStdp::additive(0.1, 0.12, 20.0, 20.0);
+
+    // pre before post potentiates, post before pre depresses, both
+    // decaying with the time between the spikes.
+    assert_close(0.1 * (-0.25 as Num).exp(), eff_d(stdp(), &[10.0], &[15.0]));
+    assert_close(
+        -0.12 * (-0.25 as Num).exp(),
+        eff_d(stdp(), &[15.0], &[10.0]),
+    );
+    assert!(eff_d(stdp(), &[10.0], &[40.0]) < eff_d(stdp(), &[10.0], &[15.0]));
+
+    // soft bounds scale potentiation by the distance to the upper bound
+    let multiplicative = PairStdp::multiplicative(0.1, 0.12, 20.0, 20.0).with_bounds(0.0, 2.0);
+    assert_close(
+        0.1 * (-0.25 as Num).exp(),
+        eff_d(multiplicative, &[10.0], &[15.0]),
+    );
+}
+
+#[test]
+fn test_triplet_stdp() {
+    let stdp = TripletStdp::hippocampal;
+    let pair = eff_d(stdp(), &[10.0], &[15.0]);
+    assert_close(5.0e-10 * (-5.0 / 16.8 as Num).exp(), pair);
+    assert_close(
+        -7.0e-3 * (-5.0 / 33.7 as Num).exp(),
+        eff_d(stdp(), &[15.0], &[10.0]),
+    );
+
+    // a preceding post-synaptic spike makes potentiation much stronger
+    let triplet = eff_d(stdp(), &[10.0], &[5.0, 15.0]) - eff_d(stdp(), &[10.0], &[5.0]);
+    assert_close(
+        (-5.0 / 16.8 as Num).exp() * (5.0e-10 + 6.2e-3 * (-10.0 / 125.0 as Num).exp()),
+        triplet,
+    );
+    assert!(triplet > 1000.0 * pair);
+}
+
+#[test]
+fn test_reward_stdp() {
+    // pairings only change the eligibility trace
+    let eligibility = eff_d(RewardStdp::default(), &[10.0], &[15.0]);
+    assert_close(0.005 * (-0.25 as Num).exp(), eligibility);
+
+    // the weight follows eligibility times dopamine, both decaying
+    let weight_change = |eligibility: Num, reward: Num| {
+        let (mut network, _, _, synapse) = pair();
+        let mut sim = Simulator::new(SynapseDelay::new(2), StdpConfig::default());
+        sim.set_plasticity_rule(RewardStdp::default());
+        network.synapse_mut(synapse).set_eff_d(eligibility);
+        network.deliver_reward(reward);
+        for _ in 0..200 {
+            sim.step(&mut network, &mut DummyRecorder);
+        }
+        assert!((network.dopamine() - reward * (-1.0 as Num).exp()).abs() < 1.0e-4);
+        for _ in 0..2000 {
+            sim.step(&mut network, &mut DummyRecorder);
+        }
+        network.synapse_ref(synapse).weight() - 1.0
+    };
+
+    // integral of c * d = c * d / (1 / tau_c + 1 / tau_d)
+    let expected = 0.001 * 0.5 / (1.0 / 1000.0 + 1.0 / 200.0);
+    assert!((weight_change(0.001, 0.5) - expected).abs() < 0.02 * expected);
+    assert!((weight_change(-0.001, 0.5) + expected).abs() < 0.02 * expected);
+    assert_eq!(0.0, weight_change(0.001, 0.0));
+}
+
+/// The firing rate (in Hz) of a neuron driven by `current`, in the last
+/// second of 20 s of homeostatic regulation towards 5 Hz.
+fn regulated_rate(neuron: NeuronConfig, current: Num, homeostasis: Homeostasis) -> Num {
+    let mut builder = NetworkBuilder::new();
+    let driver = builder.create_neuron(NeuronConfig::regular_spiking());
+    let neuron = builder.create_neuron(neuron);
+    builder.connect(driver, neuron, SynapseDelay::new(1), 5.0, Receptor::Current);
+    let mut network = builder.into_network();
+    network.set_external_input(driver, 10.0);
+    network.set_external_input(neuron, current);
+
+    let mut sim = Simulator::new(SynapseDelay::new(2), StdpConfig::default());
+    sim.set_homeostasis(&network, homeostasis, 100.0);
+    let mut recorder = FireRecorder::new();
+    for _ in 0..20_000 {
+        sim.step(&mut network, &mut recorder);
+    }
+    let spikes = recorder.spike_times(neuron);
+    spikes.iter().filter(|&&t| t >= 19_000.0).count() as Num
+}
+
+#[test]
+fn test_homeostasis_converges_to_target_rate() {
+    let unregulated = regulated_rate(NeuronConfig::regular_spiking(), 10.0, Homeostasis::new(5.0));
+    assert!(unregulated > 10.0);
+
+    let mut homeostasis = Homeostasis::new(5.0);
+    homeostasis.tau_rate = 500.0;
+    for &(excitability, intrinsic_rate) in &[
+        (Excitability::Threshold, 0.05),
+        (Excitability::Adaptation, 0.2),
+    ] {
+        let homeostasis = homeostasis
+            .clone()
+            .with_intrinsic_plasticity(excitability, intrinsic_rate);
+        let rate = regulated_rate(NeuronConfig::regular_spiking(), 10.0, homeostasis);
+        assert!((rate - 5.0).abs() <= 2.0, "{:?}: {} Hz", excitability, rate);
+    }
+
+    let scaling = homeostasis.with_synaptic_scaling(0.05);
+    let rate = regulated_rate(NeuronConfig::regular_spiking(), 0.0, scaling);
+    assert!((rate - 5.0).abs() <= 2.0, "synaptic scaling: {} Hz", rate);
+}
+
+/// Whether `homeostasis` is rejected by the `Simulator` for a network
+/// containing a neuron of `model`.
+fn rejects(model: impl NeuronModel + 'static, homeostasis: Homeostasis) -> bool {
+    let mut builder = NetworkBuilder::new();
+    builder.create_neuron(NeuronConfig::regular_spiking());
+    builder.create_neuron(model);
+    let network = builder.into_network();
+    std::panic::catch_unwind(AssertUnwindSafe(|| {
+        let mut sim = Simulator::new(SynapseDelay::new(2), StdpConfig::default());
+        sim.set_homeostasis(&network, homeostasis, 100.0);
+    }))
+    .is_err()
+}
+
+#[test]
+fn test_intrinsic_plasticity_requires_the_parameter() {
+    let threshold = Homeostasis::new(5.0).with_intrinsic_plasticity(Excitability::Threshold, 0.01);
+    let adaptation =
+        Homeostasis::new(5.0).with_intrinsic_plasticity(Excitability::Adaptation, 0.01);
+    assert!(rejects(HodgkinHuxleyConfig::default(), threshold.clone()));
+    assert!(rejects(HodgkinHuxleyConfig::default(), adaptation.clone()));
+    assert!(!rejects(LifConfig::default(), threshold.clone()));
+    assert!(rejects(LifConfig::default(), adaptation.clone()));
+    assert!(!rejects(AdExConfig::default(), adaptation));
+
+    // synaptic scaling works for all models
+    let scaling = Homeostasis::new(5.0).with_synaptic_scaling(0.01);
+    assert!(!rejects(HodgkinHuxleyConfig::default(), scaling));
+}
+
+/// The weights of a small recurrent network after 300 ms with the default
+/// rule, as computed by the original `spnet` port.
+const SPNET_WEIGHTS: [Num; 40] = [
+    2.5032468, 2.7125835, 2.8725193, 2.4738595, 2.5399153, 2.6028543, 2.7116792, 2.5665507,
+    2.7829633, 2.8994775, 3.2512796, 3.2718186, 3.4877198, 3.2448244, 2.9203036, 3.1373587,
+    3.0326355, 3.6241796, 3.5584624, 3.5759132, 2.883257, 2.7918346, 4.0162506, 2.865506,
+    3.6747231, 3.3503833, 2.8775225, 4.0251412, 4.017737, 3.180875, 4.3906193, 4.464518, 4.610182,
+    4.0577645, 4.4238815, 4.040985, 3.7409577, 4.151149, 4.114705, 4.359429,
+];
+
+#[test]
+fn test_spnet_stdp_matches_spnet() {
+    let mut builder = NetworkBuilder::new();
+    let neurons: Vec<_> = (0..20)
+        .map(|_| builder.create_neuron(NeuronConfig::regular_spiking()))
+        .collect();
+    for i in 0..20 {
+        for &j in &[(i * 7 + 3) % 20, (i * 3 + 1) % 20] {
+            if j != i {
+                let delay = SynapseDelay::new(1 + (i % 5) as u8);
+                builder.connect(neurons[i], neurons[j], delay, 2.0, Receptor::Current);
+            }
+        }
+    }
+    let mut network = builder.into_network();
+    for (i, &neuron) in neurons.iter().enumerate() {
+        network.set_external_input(neuron, 4.0 + 0.3 * i as Num);
+    }
+
+    let mut sim = Simulator::new(SynapseDelay::new(6), StdpConfig::default());
+    for t in 1..=300 {
+        sim.step(&mut network, &mut DummyRecorder);
+        if t % 50 == 0 {
+            sim.update_weights(&mut network);
+        }
+    }
+
+    let weights: Vec<Num> = network
+        .synapse_ids()
+        .map(|synapse| network.synapse_ref(synapse).weight())
+        .collect();
+    assert_eq!(SPNET_WEIGHTS.len(), weights.len());
+    for (&expected, &actual) in SPNET_WEIGHTS.iter().zip(weights.iter()) {
+        assert!((expected - actual).abs() < 1.0e-4);
+    }
+}
+
+#[test]
+fn test_reused_slots_do_not_inherit_plasticity_state() {
+    // the trace of a removed post-synaptic neuron does not depress the
+    // synapse onto the neuron reusing its slot
+    let mut stdp = PairStdp::additive(0.1, 0.12, 20.0, 20.0);
+    let (mut network, pre, post, synapse) = pair();
+    stdp.pre_spike(&mut network, synapse, 5.0);
+    stdp.post_spike(&mut network, 0, post, 10.0);
+    assert!(network.synapse_ref(synapse).eff_d() > 0.0);
+    network.remove_neuron(post);
+    let new_post = network.add_neuron(NeuronConfig::regular_spiking());
+    assert_eq!(post.index(), new_post.index());
+    let new_synapse = network.connect(pre, new_post, SynapseDelay::new(1), 1.0, Receptor::Current);
+    assert_eq!(synapse.index(), new_synapse.index());
+    stdp.pre_spike(&mut network, new_synapse, 15.0);
+    assert_eq!(0.0, network.synapse_ref(new_synapse).eff_d());
+
+    // neither does the pre-synaptic trace of the removed synapse potentiate it
+    let mut stdp = PairStdp::additive(0.1, 0.12, 20.0, 20.0);
+    let (mut network, pre, post, synapse) = pair();
+    stdp.pre_spike(&mut network, synapse, 5.0);
+    network.disconnect(synapse);
+    let new_synapse = network.connect(pre, post, SynapseDelay::new(1), 1.0, Receptor::Current);
+    stdp.post_spike(&mut network, 0, post, 10.0);
+    assert_eq!(0.0, network.synapse_ref(new_synapse).eff_d());
+
+    // the firing rate of a removed neuron is not attributed to the new one
+    let (mut network, pre, _, _) = pair();
+    network.set_external_input(pre, 10.0);
+    let mut sim = Simulator::new(SynapseDelay::new(2), StdpConfig::default());
+    sim.set_homeostasis(&network, Homeostasis::new(5.0), 100.0);
+    for _ in 0..1000 {
+        sim.step(&mut network, &mut DummyRecorder);
+    }
+    let time = sim.current_time();
+    assert!(sim.homeostasis().unwrap().firing_rate(pre, time) > 5.0);
+    network.remove_neuron(pre);
+    let new_pre = network.add_neuron(NeuronConfig::regular_spiking());
+    assert_eq!(pre.index(), new_pre.index());
+    assert_eq!(0.0, sim.homeostasis().unwrap().firing_rate(new_pre, time));
+}
+
+#[test]
+fn test_structural_plasticity_turnover() {
+    // synapses weaker than the threshold are pruned after the delay
+    let mut builder = NetworkBuilder::new();
+    let neurons: Vec<_> = (0..3)
+        .map(|_| builder.create_neuron(NeuronConfig::regular_spiking()))
+        .collect();
+    for (i, &weight) in [0.5, -0.5, 2.0].iter().enumerate() {
+        let post = neurons[(i + 1) % 3];
+        builder.connect(
+            neurons[i],
+            post,
+            SynapseDelay::new(1),
+            weight,
+            Receptor::Current,
+        );
+    }
+    let mut network = builder.into_network();
+    let mut structural = StructuralPlasticity::new(1).with_pruning(1.0, 100.0);
+    assert_eq!(0, structural.update(&mut network, 0.0).pruned);
+    assert_eq!(0, structural.update(&mut network, 50.0).pruned);
+    let turnover = structural.update(&mut network, 100.0);
+    assert_eq!(2, turnover.pruned);
+    assert_eq!(1, turnover.synapses);
+    assert_eq!((0, 2), structural.total_turnover());
+    assert_eq!(3, structural.history().len());
+
+    // co-active neurons are connected once, with clamped delays. Here `a`
+    // fires in the same steps as `b`, but is updated first.
+    let mut builder = NetworkBuilder::new();
+    let a = builder.create_neuron(NeuronConfig::regular_spiking());
+    let b = builder.create_neuron(NeuronConfig::regular_spiking());
+    let mut network = builder.into_network();
+    network.set_external_input(a, 10.0);
+    network.set_external_input(b, 10.0);
+    let mut sim = Simulator::new(SynapseDelay::new(2), StdpConfig::default());
+    assert_eq!(None, StructuralPlasticity::new(1).max_delay);
+    let structural = StructuralPlasticity::new(1)
+        .with_growth(1.0, 0.1, SynapseDelay::new(50))
+        .with_max_delay(SynapseDelay::new(5));
+    sim.set_structural_plasticity(structural, 100.0);
+    for _ in 0..1000 {
+        sim.step(&mut network, &mut DummyRecorder);
+    }
+    let structural = sim.structural_plasticity().unwrap();
+    assert_eq!(10, structural.history().len());
+    assert_eq!((1, 0), structural.total_turnover());
+    assert_eq!(1, network.total_synapses());
+    let synapse = network.neuron_ref(b).pre_synapses()[0];
+    assert_eq!(a, network.synapse_ref(synapse).pre_neuron());
+    assert_eq!(
+        SynapseDelay::new(5),
+        network.synapse_ref(synapse).synapse_delay()
+    );
+}
+
+#[test]
+fn test_plasticity_rules_change_their_own_synapses() {
+    let mut builder = NetworkBuilder::new();
+    let regular_spiking = &mut |_| NeuronConfig::regular_spiking();
+    let pre = builder.create_population_with("pre", 4, regular_spiking);
+    let post = builder.create_population_with("post", 4, regular_spiking);
+    let all_to_all = Connector::new(Topology::ErdosRenyi(1.0), 2.0);
+    let projections: Vec<ProjectionId> = [
+        SynapseConfig::default(),
+        SynapseConfig::default().with_plasticity_rule(1),
+        SynapseConfig::default().fixed(),
+    ]
+    .iter()
+    .enumerate()
+    .map(|(i, &synapse)| {
+        let name = format!("projection {}", i);
+        builder.project(&name, pre, post, &all_to_all.with_synapse(synapse))
+    })
+    .collect();
+    let mut network = builder.into_network();
+    network.set_population_input(pre, 10.0);
+
+    let mut sim = Simulator::new(SynapseDelay::new(2), StdpConfig::default());
+    assert_eq!(
+        1,
+        sim.add_plasticity_rule(PairStdp::additive(0.1, 0.12, 20.0, 20.0))
+    );
+    for _ in 0..500 {
+        sim.step(&mut network, &mut DummyRecorder);
+    }
+    let synapses =
+        |network: &Network, i: usize| network.projection(projections[i]).synapses().to_vec();
+    let eff_d: Vec<Num> = synapses(&network, 0)
+        .iter()
+        .map(|&synapse| network.synapse_ref(synapse).eff_d())
+        .collect();
+    sim.update_weights(&mut network);
+
+    // `SpnetStdp` decays `eff_d`, `PairStdp` resets it
+    for (&synapse, &eff_d) in synapses(&network, 0).iter().zip(eff_d.iter()) {
+        assert_ne!(0.0, eff_d);
+        let synapse = network.synapse_ref(synapse);
+        assert_close(2.0 + eff_d, synapse.weight());
+        assert_close(0.9 * eff_d, synapse.eff_d());
+    }
+    for &synapse in synapses(&network, 1).iter() {
+        assert_ne!(2.0, network.synapse_ref(synapse).weight());
+        assert_eq!(0.0, network.synapse_ref(synapse).eff_d());
+    }
+    for &synapse in synapses(&network, 2).iter() {
+        assert_eq!(2.0, network.synapse_ref(synapse).weight());
+        assert_eq!(0.0, network.synapse_ref(synapse).eff_d());
+    }
+
+    // outside the simulator, all plastic synapses are updated
+    for i in 0..3 {
+        for synapse in synapses(&network, i) {
+            network.synapse_mut(synapse).set_eff_d(0.5);
+        }
+    }
+    let weights: Vec<Num> = network
+        .synapse_ids()
+        .map(|synapse| network.synapse_ref(synapse).weight())
+        .collect();
+    network.update_synapse_weights(0.0, 10.0, 1.0);
+    for (synapse, weight) in network.synapse_ids().zip(weights) {
+        let synapse = network.synapse_ref(synapse);
+        let change = if synapse.plasticity_rule().is_some() {
+            0.5
+        } else {
+            0.0
+        };
+        assert_close(weight + change, synapse.weight());
+    }
+}
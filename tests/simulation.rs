@@ -0,0 +1,256 @@
+// Checks the behaviour of the simulator on small networks.
+
+use izhikevich_neurons::model::{NeuronConfig, Receptor, StdpConfig};
+use izhikevich_neurons::network::{
+    Network, NetworkBuilder, NeuronId, RefractoryPeriod, SynapseDelay,
+};
+use izhikevich_neurons::simulation::{FireRecorder, Noise, Simulator, Time};
+use izhikevich_neurons::Num;
+
+/// Two regular spiking neurons, the first driven by a constant current and
+/// connected to the second by a synapse strong enough to make it fire as
+/// soon as a spike arrives.
+fn pair(delay: SynapseDelay) -> (Network, NeuronId, NeuronId) {
+    let mut builder = NetworkBuilder::new();
+    let pre = builder.create_neuron(NeuronConfig::regular_spiking());
+    let post = builder.create_neuron(NeuronConfig::regular_spiking());
+    builder.connect(pre, post, delay, 1000.0, Receptor::Current);
+    let mut network = builder.into_network();
+    network.set_external_input(pre, 20.0);
+    (network, pre, post)
+}
+
+fn run(sim: &mut Simulator, network: &mut Network, duration: Time) -> FireRecorder {
+    let mut recorder = FireRecorder::new();
+    while sim.current_time() < duration {
+        sim.step(network, &mut recorder);
+    }
+    recorder
+}
+
+#[test]
+fn test_delays_do_not_depend_on_time_step() {
+    for &dt in &[1.0, 0.5, 0.25] {
+        let delay = SynapseDelay::from_ms(5.0);
+        let (mut network, pre, post) = pair(delay);
+        let mut sim = Simulator::with_time_step(dt, SynapseDelay::new(8), StdpConfig::default());
+        let recorder = run(&mut sim, &mut network, 100.0);
+
+        let pre_spike = recorder.spike_times(pre)[0];
+        let post_spike = recorder.spike_times(post)[0];
+        // the neuron reaching its peak fires in the following step
+        let latency = (post_spike - pre_spike) as Num - dt;
+        assert!((latency - delay.as_ms()).abs() < 1.0e-6);
+    }
+}
+
+#[test]
+fn test_weight_accumulation_precision() {
+    assert_eq!(
+        std::mem::size_of::<Num>(),
+        if cfg!(feature = "f64") { 8 } else { 4 }
+    );
+
+    let mut builder = NetworkBuilder::new();
+    let a = builder.create_neuron(NeuronConfig::regular_spiking());
+    let b = builder.create_neuron(NeuronConfig::regular_spiking());
+    let synapse = builder.connect(a, b, SynapseDelay::new(1), 1.0, Receptor::Current);
+    let mut network = builder.into_network();
+
+    // many small weight changes, as in long runs of STDP
+    network.synapse_mut(synapse).set_eff_d(1.0e-4);
+    for _ in 0..10_000 {
+        network.update_synapse_weights(0.0, 10.0, 1.0);
+    }
+    let error = (network.synapse_ref(synapse).weight() - 2.0).abs();
+    assert!(error < 10_000.0 * Num::EPSILON);
+}
+
+/// The spike times of 10 unconnected regular spiking neurons driven by
+/// `noise` for one second.
+fn noise_driven(noise: Noise, seed: u64) -> Vec<Vec<Time>> {
+    let mut builder = NetworkBuilder::new();
+    let neurons: Vec<_> = (0..10)
+        .map(|_| builder.create_neuron(NeuronConfig::regular_spiking()))
+        .collect();
+    let mut network = builder.into_network();
+    let mut sim = Simulator::new(SynapseDelay::new(2), StdpConfig::default());
+    sim.add_noise(noise, &neurons, seed);
+    let recorder = run(&mut sim, &mut network, 1000.0);
+    neurons
+        .iter()
+        .map(|&neuron| recorder.spike_times(neuron))
+        .collect()
+}
+
+#[test]
+fn test_noise_is_reproducible() {
+    for &noise in &[
+        Noise::GaussianWhite {
+            mean: 0.0,
+            std: 5.0,
+        },
+        Noise::OrnsteinUhlenbeck {
+            mean: 0.0,
+            std: 5.0,
+            tau: 5.0,
+        },
+        Noise::Poisson {
+            rate: 20.0,
+            weight: 1000.0,
+        },
+    ] {
+        let spikes = noise_driven(noise, 1);
+        assert!(spikes.iter().any(|spikes| !spikes.is_empty()));
+        assert_eq!(spikes, noise_driven(noise, 1));
+        assert_ne!(spikes, noise_driven(noise, 2));
+    }
+}
+
+#[test]
+fn test_poisson_noise_rate() {
+    // every input spike is strong enough to make the neuron fire
+    let noise = Noise::Poisson {
+        rate: 20.0,
+        weight: 1000.0,
+    };
+    let spikes: usize = noise_driven(noise, 3).iter().map(Vec::len).sum();
+    // 200 expected spikes, with a standard deviation of about 14
+    assert!(spikes > 150 && spikes < 250);
+}
+
+/// The shortest interval between the spikes of a fast spiking neuron driven
+/// by a strong current, simulated with time step `dt`.
+fn shortest_interval(dt: Num, refractory_period: Option<RefractoryPeriod>) -> Time {
+    let mut builder = NetworkBuilder::new();
+    let neuron = builder.create_neuron(NeuronConfig::fast_spiking());
+    let mut network = builder.into_network();
+    network.set_external_input(neuron, 30.0);
+    network
+        .neuron_mut(neuron)
+        .set_refractory_period(refractory_period);
+    let mut sim = Simulator::with_time_step(dt, SynapseDelay::new(2), StdpConfig::default());
+    let spikes = run(&mut sim, &mut network, 500.0).spike_times(neuron);
+    assert!(spikes.len() > 5);
+    spikes
+        .windows(2)
+        .map(|w| w[1] - w[0])
+        .fold(Time::INFINITY, Time::min)
+}
+
+#[test]
+fn test_refractory_period() {
+    assert!(shortest_interval(1.0, None) < 20.0);
+    assert!(shortest_interval(1.0, Some(RefractoryPeriod::Steps(20))) > 20.0);
+    assert!(shortest_interval(0.5, Some(RefractoryPeriod::Steps(20))) < 20.0);
+    for &dt in &[1.0, 0.5] {
+        assert!(shortest_interval(dt, Some(RefractoryPeriod::Ms(20.0))) > 20.0);
+    }
+
+    // spikes arriving within the period are ignored
+    let (mut network, pre, post) = pair(SynapseDelay::new(1));
+    network
+        .neuron_mut(post)
+        .set_refractory_period(Some(RefractoryPeriod::Ms(1000.0)));
+    let mut sim = Simulator::new(SynapseDelay::new(2), StdpConfig::default());
+    let recorder = run(&mut sim, &mut network, 500.0);
+    assert!(recorder.spike_times(pre).len() > 5);
+    assert_eq!(1, recorder.spike_times(post).len());
+    assert!(network.neuron_ref(post).is_refractory());
+}
+
+#[test]
+fn test_reused_slots_have_a_new_generation() {
+    let (mut network, pre, post) = pair(SynapseDelay::new(1));
+    let synapse = network.synapse_ids().next().unwrap();
+
+    // removing a neuron removes its synapses
+    network.remove_neuron(post);
+    assert!(!network.contains_neuron(post));
+    assert!(!network.contains_synapse(synapse));
+    assert_eq!(None, network.max_synapse_delay());
+
+    let new_post = network.add_neuron(NeuronConfig::regular_spiking());
+    assert_eq!(post.index(), new_post.index());
+    assert_eq!(post.generation() + 1, new_post.generation());
+    assert!(!network.contains_neuron(post));
+    assert!(network.contains_neuron(new_post));
+
+    let new_synapse = network.connect(pre, new_post, SynapseDelay::new(1), 1.0, Receptor::Current);
+    assert_eq!(synapse.index(), new_synapse.index());
+    assert_eq!(synapse.generation() + 1, new_synapse.generation());
+    assert!(!network.contains_synapse(synapse));
+    assert_eq!(vec![new_synapse], network.synapse_ids().collect::<Vec<_>>());
+}
+
+/// Steps `sim` until `neuron` fires, and returns the time of the spike.
+fn run_until_spike(
+    sim: &mut Simulator,
+    network: &mut Network,
+    recorder: &mut FireRecorder,
+    neuron: NeuronId,
+) -> Time {
+    while recorder.spike_times(neuron).is_empty() {
+        sim.step(network, recorder);
+    }
+    recorder.spike_times(neuron)[0]
+}
+
+#[test]
+fn test_spikes_in_flight() {
+    // a spike travelling along a removed synapse is dropped
+    let (mut network, pre, post) = pair(SynapseDelay::new(10));
+    let synapse = network.synapse_ids().next().unwrap();
+    let mut sim = Simulator::new(SynapseDelay::new(16), StdpConfig::default());
+    let mut recorder = FireRecorder::new();
+    run_until_spike(&mut sim, &mut network, &mut recorder, pre);
+    network.disconnect(synapse);
+    while sim.current_time() < 50.0 {
+        sim.step(&mut network, &mut recorder);
+    }
+    assert!(recorder.spike_times(post).is_empty());
+
+    // a spike travelling along a rewired synapse arrives at the new
+    // post-synaptic neuron
+    let (mut network, pre, post) = pair(SynapseDelay::new(10));
+    let synapse = network.synapse_ids().next().unwrap();
+    let other = network.add_neuron(NeuronConfig::regular_spiking());
+    let mut sim = Simulator::new(SynapseDelay::new(16), StdpConfig::default());
+    let mut recorder = FireRecorder::new();
+    let pre_spike = run_until_spike(&mut sim, &mut network, &mut recorder, pre);
+    network.rewire(synapse, pre, other);
+    let other_spike = run_until_spike(&mut sim, &mut network, &mut recorder, other);
+    assert_eq!(11.0, other_spike - pre_spike);
+    assert!(recorder.spike_times(post).is_empty());
+}
+
+#[test]
+fn test_delays_longer_than_the_spike_buffer() {
+    let mut builder = NetworkBuilder::new();
+    let pre = builder.create_neuron(NeuronConfig::regular_spiking());
+    let near = builder.create_neuron(NeuronConfig::regular_spiking());
+    let far = builder.create_neuron(NeuronConfig::regular_spiking());
+    builder.connect(pre, near, SynapseDelay::new(3), 1000.0, Receptor::Current);
+    let mut network = builder.into_network();
+    network.set_external_input(pre, 20.0);
+    let mut sim = Simulator::new(network.max_synapse_delay().unwrap(), StdpConfig::default());
+
+    // the buffer grows while the spike to `near` is travelling
+    network.connect(pre, far, SynapseDelay::new(20), 1000.0, Receptor::Current);
+    let recorder = run(&mut sim, &mut network, 200.0);
+    let pre_spikes = recorder.spike_times(pre);
+    assert!(pre_spikes.len() > 2);
+    for (delay, neuron) in &[(3.0, near), (20.0, far)] {
+        let spikes = recorder.spike_times(*neuron);
+        assert_eq!(
+            spikes.len(),
+            pre_spikes
+                .iter()
+                .filter(|&&t| t + delay + 1.0 < 200.0)
+                .count()
+        );
+        for (pre_spike, spike) in pre_spikes.iter().zip(spikes.iter()) {
+            assert_eq!(delay + 1.0, spike - pre_spike);
+        }
+    }
+}
@@ -0,0 +1,230 @@
+// Checks how single synapses act on their post-synaptic neuron.
+
+use izhikevich_neurons::model::{
+    CurrentKernel, Dendrite, MultiCompartmentConfig, NeuronConfig, Receptor, ShortTermPlasticity,
+    SimpleModelConfig, StdpConfig,
+};
+use izhikevich_neurons::network::{NetworkBuilder, SynapseConfig, SynapseDelay};
+use izhikevich_neurons::simulation::{DummyRecorder, Simulator};
+use izhikevich_neurons::Num;
+
+const DURATION: usize = 200;
+
+/// The potential of a neuron at rest, receiving spikes at `spike_times` (in
+/// ms) through a synapse of `synapse` and `weight`, minus its potential
+/// without the synapse.
+fn response(synapse: impl Into<SynapseConfig>, weight: Num, spike_times: &[usize]) -> Vec<Num> {
+    let synapse = synapse.into();
+    let trace = |weight: Num| {
+        let mut builder = NetworkBuilder::new();
+        let pre = builder.create_neuron(NeuronConfig::regular_spiking());
+        let post = builder.create_neuron(NeuronConfig::regular_spiking());
+        builder.connect(pre, post, SynapseDelay::new(1), weight, synapse);
+        let mut network = builder.into_network();
+        let mut sim = Simulator::new(SynapseDelay::new(2), StdpConfig::default());
+
+        let mut potentials = Vec::new();
+        for t in 0..DURATION {
+            // the pre-synaptic neuron peaks in this step and fires in the
+            // next, so that the spike arrives at `t + 2`.
+            let input = if spike_times.contains(&(t + 2)) {
+                1000.0
+            } else {
+                0.0
+            };
+            network.set_external_input(pre, input);
+            sim.step(&mut network, &mut DummyRecorder);
+            potentials.push(network.neuron_ref(post).potential());
+        }
+        potentials
+    };
+    let control = trace(0.0);
+    trace(weight)
+        .iter()
+        .zip(control.iter())
+        .map(|(v, c)| v - c)
+        .collect()
+}
+
+#[test]
+fn test_receptors() {
+    let ampa = response(Receptor::Ampa, 0.05, &[10]);
+    let nmda = response(Receptor::Nmda, 0.05, &[10]);
+    let gaba_b = response(Receptor::GabaB, 0.05, &[10]);
+
+    assert!(ampa[..10].iter().all(|&v| v == 0.0));
+    assert!(ampa[10] > 1.0);
+    assert!(gaba_b[10] < -0.5);
+
+    // at rest, the magnesium block keeps NMDA receptors almost closed
+    assert!(nmda[10] > 0.0 && nmda[10] < 0.1 * ampa[10]);
+}
+
+/// The index of the largest value.
+fn argmax(values: &[Num]) -> usize {
+    (0..values.len())
+        .max_by(|&i, &j| values[i].partial_cmp(&values[j]).unwrap())
+        .unwrap()
+}
+
+#[test]
+fn test_current_kernels() {
+    let pulse = response(CurrentKernel::Pulse, 2.0, &[10]);
+    let exponential = response(CurrentKernel::Exponential { tau: 10.0 }, 0.5, &[10]);
+    let alpha = response(CurrentKernel::Alpha { tau: 10.0 }, 0.5, &[10]);
+
+    assert!(exponential[..10].iter().all(|&v| v == 0.0));
+    assert!(alpha[..10].iter().all(|&v| v == 0.0));
+
+    // the pulse acts within a single step, the kernels keep depolarizing
+    // the neuron for about their time constant, the alpha kernel peaking
+    // later.
+    assert_eq!(10, argmax(&pulse));
+    assert!(argmax(&exponential) > 10);
+    assert!(argmax(&alpha) > argmax(&exponential) + 5);
+    assert!(pulse[20].abs() < 0.05);
+    assert!(exponential[20] > 0.2 && alpha[20] > 0.5);
+}
+
+#[test]
+#[should_panic(expected = "requires Receptor::Current")]
+fn test_current_kernel_of_conductance_synapse() {
+    let synapse = SynapseConfig {
+        receptor: Receptor::Ampa,
+        kernel: CurrentKernel::Exponential { tau: 5.0 },
+        ..Default::default()
+    };
+    let _ = response(synapse, 0.05, &[10]);
+}
+
+/// The change of the potential in the step each spike arrives.
+fn jumps(response: &[Num], spike_times: &[usize]) -> Vec<Num> {
+    spike_times
+        .iter()
+        .map(|&t| response[t] - response[t - 1])
+        .collect()
+}
+
+#[test]
+fn test_short_term_plasticity() {
+    let spike_times = [10, 30, 50, 70, 90];
+    let stp = |stp| SynapseConfig {
+        stp: Some(stp),
+        ..Default::default()
+    };
+
+    let depressing = jumps(
+        &response(stp(ShortTermPlasticity::depressing()), 4.0, &spike_times),
+        &spike_times,
+    );
+    assert!(depressing.windows(2).all(|w| w[1] < w[0]));
+    assert!(depressing[4] < depressing[0] / 2.0);
+
+    let facilitating = jumps(
+        &response(stp(ShortTermPlasticity::facilitating()), 40.0, &spike_times),
+        &spike_times,
+    );
+    assert!(facilitating.windows(2).all(|w| w[1] > w[0]));
+    assert!(facilitating[4] > facilitating[0] * 2.0);
+}
+
+/// The potentials of two coupled regular spiking neurons after 500 ms, the
+/// first receiving `current`.
+fn gap_junction_potentials(conductance: Num, current: Num) -> (Num, Num) {
+    let mut builder = NetworkBuilder::new();
+    let a = builder.create_neuron(NeuronConfig::regular_spiking());
+    let b = builder.create_neuron(NeuronConfig::regular_spiking());
+    builder.connect_gap_junction(a, b, conductance);
+    let mut network = builder.into_network();
+    network.set_external_input(a, current);
+    let mut sim = Simulator::new(SynapseDelay::new(2), StdpConfig::default());
+    for _ in 0..500 {
+        sim.step(&mut network, &mut DummyRecorder);
+    }
+    (
+        network.neuron_ref(a).potential(),
+        network.neuron_ref(b).potential(),
+    )
+}
+
+#[test]
+fn test_gap_junctions() {
+    let (rest, _) = gap_junction_potentials(0.0, 0.0);
+    let (a, b) = gap_junction_potentials(0.0, 3.0);
+    assert!(a > rest + 1.0);
+    assert_eq!(rest, b);
+
+    // the current flows from the depolarized to the resting neuron, which
+    // is pulled up more by a stronger coupling
+    let (a_weak, b_weak) = gap_junction_potentials(0.1, 3.0);
+    let (a_strong, b_strong) = gap_junction_potentials(0.3, 3.0);
+    assert!(a_weak < a && b_weak > rest && b_weak < a_weak);
+    assert!(b_strong > b_weak && a_strong < a_weak);
+    assert!(a_strong - b_strong < a_weak - b_weak);
+
+    // the coupling is symmetric
+    let mut builder = NetworkBuilder::new();
+    let a = builder.create_neuron(NeuronConfig::regular_spiking());
+    let b = builder.create_neuron(NeuronConfig::regular_spiking());
+    builder.connect_gap_junction(a, b, 0.1);
+    let mut network = builder.into_network();
+    network.set_external_input(b, 3.0);
+    let mut sim = Simulator::new(SynapseDelay::new(2), StdpConfig::default());
+    for _ in 0..500 {
+        sim.step(&mut network, &mut DummyRecorder);
+    }
+    assert_eq!(b_weak, network.neuron_ref(a).potential());
+    assert_eq!(a_weak, network.neuron_ref(b).potential());
+}
+
+/// The peak depolarization of the soma and the dendrite of a two-compartment
+/// neuron, when a spike arrives at `compartment`.
+fn compartment_response(compartment: usize) -> (Num, Num) {
+    let rs = SimpleModelConfig::regular_spiking();
+    let mut builder = NetworkBuilder::new();
+    let pre = builder.create_neuron(NeuronConfig::regular_spiking());
+    let post = builder.create_neuron(MultiCompartmentConfig::new(
+        rs.clone(),
+        [Dendrite::new(rs, 0, 20.0)],
+    ));
+    let synapse = SynapseConfig {
+        kernel: CurrentKernel::Exponential { tau: 5.0 },
+        ..Default::default()
+    };
+    builder.connect(
+        pre,
+        post,
+        SynapseDelay::new(1),
+        200.0,
+        synapse.at_compartment(compartment),
+    );
+    let mut network = builder.into_network();
+    let mut sim = Simulator::new(SynapseDelay::new(2), StdpConfig::default());
+
+    let (mut soma, mut dendrite) = (Num::MIN, Num::MIN);
+    let rest = network.neuron_ref(post).potential();
+    for t in 0..100 {
+        network.set_external_input(pre, if t == 8 { 1000.0 } else { 0.0 });
+        sim.step(&mut network, &mut DummyRecorder);
+        let neuron = network.neuron_ref(post);
+        soma = soma.max(neuron.compartment_potential(0));
+        dendrite = dendrite.max(neuron.compartment_potential(1));
+    }
+    (soma - rest, dendrite - rest)
+}
+
+#[test]
+fn test_compartments() {
+    // inputs are attenuated on their way to the other compartment
+    let (soma, dendrite) = compartment_response(0);
+    assert!(soma > 1.0 && dendrite > 0.0 && dendrite < soma);
+    let (soma_from_dendrite, dendrite) = compartment_response(1);
+    assert!(dendrite > 1.0 && soma_from_dendrite > 0.0 && soma_from_dendrite < dendrite);
+    assert!(soma_from_dendrite < soma);
+}
+
+#[test]
+#[should_panic]
+fn test_missing_compartment() {
+    let _ = compartment_response(2);
+}